@@ -0,0 +1,116 @@
+//! Decode throughput of a 64-variant, sparse `u8` enum: `from_repr`, which decodes
+//! through a lookup table when the values span few enough, against a plain `match`
+//! (as `checked_from` still uses). Run with `cargo bench --features bench`.
+#![allow(clippy::print_stdout)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+yikes_intenum::yikes_intenum! {
+    enum Wide(u8) {
+        V00 = 0x09,
+        V01 = 0x0b,
+        V02 = 0x0c,
+        V03 = 0x0e,
+        V04 = 0x0f,
+        V05 = 0x10,
+        V06 = 0x11,
+        V07 = 0x12,
+        V08 = 0x16,
+        V09 = 0x17,
+        V10 = 0x18,
+        V11 = 0x1a,
+        V12 = 0x1e,
+        V13 = 0x1f,
+        V14 = 0x22,
+        V15 = 0x24,
+        V16 = 0x26,
+        V17 = 0x2e,
+        V18 = 0x30,
+        V19 = 0x36,
+        V20 = 0x38,
+        V21 = 0x39,
+        V22 = 0x3d,
+        V23 = 0x4a,
+        V24 = 0x4e,
+        V25 = 0x5d,
+        V26 = 0x5f,
+        V27 = 0x65,
+        V28 = 0x6b,
+        V29 = 0x6c,
+        V30 = 0x6f,
+        V31 = 0x81,
+        V32 = 0x89,
+        V33 = 0x8a,
+        V34 = 0x8c,
+        V35 = 0x8d,
+        V36 = 0x8e,
+        V37 = 0x8f,
+        V38 = 0x90,
+        V39 = 0x92,
+        V40 = 0x93,
+        V41 = 0x94,
+        V42 = 0x95,
+        V43 = 0xa0,
+        V44 = 0xa1,
+        V45 = 0xa3,
+        V46 = 0xa5,
+        V47 = 0xa6,
+        V48 = 0xae,
+        V49 = 0xb6,
+        V50 = 0xd0,
+        V51 = 0xd2,
+        V52 = 0xd3,
+        V53 = 0xde,
+        V54 = 0xdf,
+        V55 = 0xe4,
+        V56 = 0xe6,
+        V57 = 0xe8,
+        V58 = 0xec,
+        V59 = 0xf2,
+        V60 = 0xf4,
+        V61 = 0xf6,
+        V62 = 0xfa,
+        V63 = 0xfc,
+    }
+}
+
+/// Times `f` over `input`, taking the best of a few rounds.
+fn time(input: &[u8], f: impl Fn(&[u8]) -> u64) -> (Duration, u64) {
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            let sum = f(black_box(input));
+            (start.elapsed(), sum)
+        })
+        .min_by_key(|&(elapsed, _)| elapsed)
+        .unwrap_or_default()
+}
+
+fn main() {
+    // every byte value, over and over, in a scrambled order.
+    let input: Vec<u8> = (0..1_u32 << 20)
+        .map(|i| i.wrapping_mul(2_654_435_761).to_be_bytes()[0])
+        .collect();
+
+    let (looked_up, looked_up_sum) = time(&input, Wide::sum_decoded);
+    let (matched, matched_sum) = time(&input, |input| {
+        input.iter().fold(0_u64, |sum, &value| {
+            // unknowns can't be made without `from_repr`; keep their values as-is.
+            let decoded = black_box(Wide::checked_from(black_box(value)));
+            sum.wrapping_add(u64::from(decoded.map_or(value, Wide::to_repr)))
+        })
+    });
+    assert_eq!(matched_sum, looked_up_sum);
+
+    #[allow(clippy::cast_precision_loss)]
+    let per_value = |elapsed: Duration| elapsed.as_nanos() as f64 / input.len() as f64;
+    println!(
+        "from_repr (lookup table): {:.3} ns/value",
+        per_value(looked_up)
+    );
+    println!(
+        "match:                    {:.3} ns/value",
+        per_value(matched)
+    );
+}
@@ -1,7 +1,37 @@
 //! A macro for mapping integers to Rust `enum`s with integer-numbered variants, plus
 //! a catch-all `Unknown` variant.
+//!
+//! # Features
+//! - `alloc`: generate helpers returning `Vec`s, like `sorted_pairs`.
+//! - `std`: generate `from_repr_logged`, for instrumenting decoding. Implies `alloc`.
+//! - `defmt`: implement `defmt::Format`, and generate `from_repr_defmt`, logging unknown
+//!   values through `defmt`. The
+//!   generated code names `defmt` directly, so the crate using the macro must depend
+//!   on it too.
+//! - `arbitrary`: implement `arbitrary::Arbitrary`, for fuzzing; any value of the
+//!   underlying integer can be generated, so unknowns are explored too.
+//! - `proptest`: implement `proptest::arbitrary::Arbitrary`, sampling the underlying
+//!   integer (so sparse enums mostly produce unknowns), and generate `known_strategy`,
+//!   sampling named variants only.
+//! - `rand`: generate `random_known`, sampling named variants uniformly.
+//! - `clap`: implement `clap::ValueEnum`, so that named variants can be taken as
+//!   command-line arguments by name.
+//! - `num_enum`: implement `num_enum::FromPrimitive`, like `From`, and
+//!   `num_enum::TryFromPrimitive`, failing for unknown values like `try_from_known`.
+//! - `schemars`: implement `schemars::JsonSchema`, describing the named variants, as
+//!   they (de)serialize, along with any other value of the underlying integer. Implies
+//!   `alloc`.
+//! - `bytemuck`: generate `from_bits`, `to_bits`, and `iter_from_bytes`, decoding a
+//!   byte buffer of the underlying integer, in native byte order, through `bytemuck`.
+//! - `zerocopy`: generate `read_from_prefix`, decoding a value from the front of a
+//!   byte buffer, in native byte order, through `zerocopy`.
+//! - `bench`: generate `sum_decoded`, a decoding workload for benchmark harnesses.
+//! - `serde`: implement `Serialize` and `Deserialize` through the underlying integer, and generate
+//!   `serialize_repr` and `deserialize_repr` for `serialize_with`/`deserialize_with`.
 
 /// A macro that implements useful functionality on integer-based `enum`s.
+///
+/// The enum may have any visibility; generated methods and error types share it.
 /// ```rust
 /// yikes_intenum::yikes_intenum! {
 ///     /// IP datagram encapsulated protocol.
@@ -21,355 +51,5151 @@
 ///     }
 /// }
 /// ```
+///
+/// # Options
+/// Generated code can be tweaked with `#[yikes(...)]` attributes on the `enum`; any
+/// other attributes are passed through untouched.
+///
+/// - `#[yikes(ord_by_declaration)]`: order variants by declaration order instead of
+///   by value. Unknown values sort after every named variant, and among themselves by
+///   value; this is also the order keys iterate in a `BTreeMap`. Comparing against the
+///   underlying integer with `<` and friends isn't possible in this mode.
+/// - `#[yikes(strict_eq)]`: treat a named variant and an unknown holding the same value
+///   as different, for when it matters which way a value was made. Hashing and
+///   ordering follow suit, so that they stay consistent with equality; the named
+///   variant orders first. Comparisons against the underlying integer still go by
+///   value alone.
+/// - `#[yikes(sorted)]`: check, at compile time, that values ascend in declaration
+///   order. An out-of-order variant is named in the error.
+/// - `#[yikes(contiguous)]`: check, at compile time, that values ascend in declaration
+///   order with no gaps, as in a dense opcode table. The variant after a gap is named
+///   in the error. For both, a range variant counts from its start to its end.
+/// - `#[yikes(ascii)]`: for `u8` enums of ASCII codes, implement `TryFrom<char>` and
+///   `TryFrom<$name> for char`. Only ASCII characters and codes convert, since any
+///   other `char` would need more than one byte (or a different encoding) to
+///   represent. Unknown values convert just like named ones, as long as they're ASCII.
+/// - `#[yikes(one_hot)]`: for flag-like enums, check at compile time that every value is
+///   a single bit, and generate `one_hot()`, returning the value as a bit mask.
+/// - `#[yikes(flags)]`: for enums of flag bits, check the same, and generate
+///   `to_mask()`, returning the value's bit, and `contains_in(mask)`, returning whether
+///   it's set in `mask`.
+/// - `#[yikes(from(Other, ...))]`: implement `From<Other>` for each named enum, such as an
+///   older version of the same table, by converting through the value. Values the
+///   enum doesn't name become its unknown variant. `Other` must be another
+///   `yikes_intenum!` enum whose repr converts losslessly into this one's.
+/// - `#[yikes(classify(pattern => "category", ...))]`: sort values into categories, such
+///   as a protocol's reserved or experimental ranges, without naming them. Generates
+///   `classify()`, returning the first category whose pattern matches, and shows the
+///   category in unknowns' `Debug` output, as in `Unknown(144, reserved)`.
+/// - `#[yikes(serde_as = "str")]`: (de)serialize named variants by name; see
+///   [Serde](#serde).
+/// - `#[yikes(unknown_tag = "...")]`: with `serde_as = "str"`, serialize unknowns under
+///   this name, as in `{"unassigned": 58}`, instead of as the bare integer; see
+///   [Serde](#serde).
+/// - `#[yikes(unknown = Other)]`: name the catch-all variant `Other` instead of
+///   `Unknown`, everywhere it appears, including `Debug` output, and `Display`
+///   output in snake case, as in `other (0x3a)`.
+/// - `#[yikes(exhaustive)]`: leave out the unknown variant, for an enum naming every
+///   value of the underlying type, as ranges allow; see `is_exhaustive()`. `From<$ty>`
+///   still can't fail, and a `match` needs no catch-all. An enum that misses a value is
+///   an error at compile time. Methods about unknowns remain, never seeing one;
+///   `match_with` still takes the unknown's closure, and never calls it.
+/// - `#[yikes(into_iter)]`: implement `IntoIterator`, yielding the value once; for
+///   generic code that takes a collection where you have a single value.
+/// - `#[yikes(no_ord)]`, `#[yikes(no_hash)]`: don't implement `PartialOrd` and `Ord`
+///   (including against the underlying integer), or `Hash`, to save on code size.
+///   Equality and conversions are unaffected.
+/// - `#[yikes(repr(...))]`: use the given `#[repr]` for the enum, such as `repr(C, u8)`
+///   for FFI, instead of the default `repr($ty)`.
+/// - `#[yikes(step)]`: implement `Step`, so that ranges of the enum can be iterated,
+///   as in `for p in Protocol::Icmp..=Protocol::Udp`, going through every value in
+///   between (unknowns included). `Step` is unstable, so this is nightly-only, and the
+///   crate using the macro must enable `#![feature(step_trait)]`. Steps go by value,
+///   so don't combine this with `ord_by_declaration`.
+///
+/// Variants take options the same way:
+///
+/// - `#[yikes(long = "...")]`: a longer, descriptive name, returned by `long_name()` and
+///   shown by `display_long()`. It's written verbatim, without any escaping; `Debug`
+///   always shows the Rust identifier instead.
+/// - `#[yikes(alias)]`: allow this variant to share its value with an earlier one, as
+///   a synonym. Both names construct and compare equal, and both are listed in
+///   `VARIANTS`, but decoding the value always gives the earlier, canonical variant.
+/// - `#[yikes(default)]`: implement `Default`, returning this variant. At most one
+///   variant can be marked.
+/// - `#[yikes(parse_alias = "...")]`: another name that `FromStr`, and so the
+///   `serde_as = "str"` deserializer, accepts for this variant, as with serde's own
+///   `alias`. It's only ever read, never written: `name()`, `Display` and serializing
+///   all use the variant's own name. Repeat it for more than one.
+/// - `#[yikes(reserved)]`: mark the variant as reserved, not to be used, so that
+///   `is_reserved()` returns `true` for it. Unknowns are never reserved.
+/// - `#[yikes(display = "...")]`: what `Display` shows for this variant, such as
+///   `"Transmission Control Protocol"`, instead of its identifier. `FromStr` still only
+///   parses the identifier, so this variant no longer round-trips through a string.
+///
+/// Other attributes on variants are passed through, too. A `#[deprecated]` variant
+/// warns wherever it's used, but not from the generated code:
+/// ```rust
+/// #![deny(deprecated)]
+/// yikes_intenum::yikes_intenum! {
+///     pub enum Protocol(u8) {
+///         Tcp = 0x06,
+///         #[deprecated = "use `Tcp`"]
+///         Tp = 0x1d,
+///     }
+/// }
+/// # fn main() { let _ = Protocol::Tcp; }
+/// ```
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// # yikes_intenum::yikes_intenum! {
+/// #     pub enum Protocol(u8) {
+/// #         Tcp = 0x06,
+/// #         #[deprecated = "use `Tcp`"]
+/// #         Tp = 0x1d,
+/// #     }
+/// # }
+/// # fn main() {
+/// let _ = Protocol::Tp;
+/// # }
+/// ```
+///
+/// Values must be literals or constants of the underlying type. One that doesn't fit
+/// is an error, pointing at the value, even where `overflowing_literals` is allowed:
+/// ```compile_fail
+/// #![allow(overflowing_literals)]
+/// yikes_intenum::yikes_intenum! {
+///     pub enum Protocol(u8) {
+///         Tcp = 0x06,
+///         Huge = 300,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// A variant can also take a whole range of literals, for numbers reserved en bloc.
+/// Every value in the range decodes to it, and it encodes back to the start of the
+/// range, its canonical value; so, unlike other values, the rest of the range doesn't
+/// round-trip. A range can't overlap an earlier variant:
+/// ```rust
+/// yikes_intenum::yikes_intenum! {
+///     pub enum Protocol(u8) {
+///         Tcp = 0x06,
+///         Experimental = 0xfd..=0xfe,
+///     }
+/// }
+/// assert_eq!(Protocol::from(0xfe), Protocol::Experimental);
+/// assert_eq!(u8::from(Protocol::Experimental), 0xfd);
+/// ```
+///
+/// With ranges, an enum can name every value, and `#[yikes(exhaustive)]` then leaves
+/// out the unknown variant:
+/// ```rust
+/// yikes_intenum::yikes_intenum! {
+///     #[yikes(exhaustive)]
+///     pub enum Sign(i8) {
+///         Negative = -128..=-1,
+///         Zero = 0,
+///         Positive = 1..=127,
+///     }
+/// }
+/// let sign = match Sign::from(-5) {
+///     Sign::Negative => -1,
+///     Sign::Zero => 0,
+///     Sign::Positive => 1,
+/// };
+/// assert_eq!(sign, -1);
+/// ```
+/// Missing any value is an error:
+/// ```compile_fail
+/// yikes_intenum::yikes_intenum! {
+///     #[yikes(exhaustive)]
+///     pub enum Sign(i8) {
+///         Negative = -128..=-1,
+///         Positive = 1..=127,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// # Serde
+/// With the `serde` feature, `Serialize` and `Deserialize` are implemented, going
+/// through the underlying integer; values without a named variant deserialize to the
+/// unknown variant, rather than failing.
+///
+/// To use names instead, in human-readable formats, add `#[yikes(serde_as = "str")]`:
+/// named variants serialize as their names and unknowns as the underlying integer, and
+/// either deserializes. This relies on `deserialize_any`, so it only suits
+/// self-describing formats, like JSON or TOML. (`serde_as = "int"` is the default.)
+/// Names given with `#[yikes(parse_alias = "...")]` deserialize too, but are never
+/// serialized, so that renamed variants can still be read from old data.
+///
+/// To give unknowns a name of their own there, as `#[serde(rename = "...")]` would the
+/// catch-all variant of a serde derive, add `#[yikes(unknown_tag = "...")]`: unknowns
+/// then serialize the way serde writes a newtype variant, `{"unassigned": 58}` in JSON,
+/// and that deserializes back into the same unknown. Bare integers still deserialize
+/// as before. Without `serde_as = "str"`, the tag is unused.
+///
+/// Without it, `From<$ty>` (and so, `TryFrom<$ty>`) and `From<$name> for $ty` are
+/// always generated, so a `serde` derive can go through the underlying integer without
+/// any help from this crate:
+/// ```rust
+/// # #[cfg(not(feature = "serde"))]
+/// yikes_intenum::yikes_intenum! {
+///     #[derive(serde::Serialize, serde::Deserialize)]
+///     #[serde(try_from = "u8", into = "u8")]
+///     pub enum Protocol(u8) {
+///         Tcp = 0x06,
+///         Udp = 0x11,
+///     }
+/// }
+/// ```
+/// `#[serde(from = "u8", into = "u8")]` works just as well, since decoding can't fail.
+/// Either way, the enum also (de)serializes fine as part of a `#[serde(flatten)]`ed
+/// struct.
+///
+/// If the enum can't be given a derive (say, it's defined in another crate), the
+/// `serde` feature also generates `serialize_repr` and `deserialize_repr`, with the
+/// signatures `serialize_with` and `deserialize_with` expect:
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// # yikes_intenum::yikes_intenum! {
+/// #     pub enum Protocol(u8) {
+/// #         Tcp = 0x06,
+/// #         Udp = 0x11,
+/// #     }
+/// # }
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Header {
+///     #[serde(
+///         serialize_with = "Protocol::serialize_repr",
+///         deserialize_with = "Protocol::deserialize_repr"
+///     )]
+///     protocol: Protocol,
+/// }
+/// # }
+/// ```
 #[macro_export]
 macro_rules! yikes_intenum {
     (
-        $( #[$enum_attr:meta] )*
-        pub enum $name:ident($ty:ty) {
-            $(
-              $( #[$variant_attr:meta] )*
-              $variant:ident = $value:expr
-            ),+ $(,)?
+        $( #[$($enum_attr:tt)*] )*
+        $vis:vis enum $name:ident($ty:ident) {
+            // `$variant = $value` or `$variant = $low..=$high`, comma-separated; taken
+            // apart by `@variants`, since a range would parse as a single `expr`.
+            $($variants:tt)+
         }
     ) => {
-        paste::paste! {
-            mod [< _ $name:snake _private >] {
-                #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-                pub struct Sealed;
+        $crate::__yikes_intenum! {
+            @enum_attrs [] []
+            $( #[$($enum_attr)*] )*
+            => $vis enum $name($ty) { $($variants)+ }
+        }
+    };
+} // macro_rules! yikes_intenum
+
+// pub[(crate)] use yikes_intenum; // if not using `#[macro_export]`
+
+/// Implementation details of [`yikes_intenum!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum {
+    // split `#[yikes(...)]` options from the attributes passed through to the enum.
+    (
+        @enum_attrs [$($attrs:tt)*] [$($opts:tt)*]
+        #[yikes($( $key:ident $(= $val:tt)? $(($($args:tt)*))? ),* $(,)?)]
+        $($rest:tt)*
+    ) => {
+        $crate::__yikes_intenum! {
+            @enum_attrs [$($attrs)*] [$($opts)* $( [$key $(= $val)? $(($($args)*))?] )*]
+            $($rest)*
+        }
+    };
+    (
+        @enum_attrs [$($attrs:tt)*] [$($opts:tt)*]
+        #[$($attr:tt)*]
+        $($rest:tt)*
+    ) => {
+        $crate::__yikes_intenum! {
+            @enum_attrs [$($attrs)* #[$($attr)*]] [$($opts)*]
+            $($rest)*
+        }
+    };
+    (
+        @enum_attrs [$($attrs:tt)*] [$($opts:tt)*]
+        => $vis:vis enum $name:ident($ty:ident) { $($variants:tt)* }
+    ) => {
+        $crate::__yikes_intenum! {
+            @variants [[$($attrs)*] [$($opts)*] [$vis] $name $ty] [] [] []
+            $($variants)*
+        }
+    };
+
+    // the same again for each variant, collecting
+    // `{ [attributes] $variant = $value; [options] }`.
+    (
+        @variants $header:tt [$($done:tt)*] [] []
+        $(
+            $( #[
+                $(yikes($( $key:ident $(= $val:tt)? $(($($args:tt)*))? ),* $(,)?))?
+                $(doc $(= $doc:literal)? $(($($doc_args:tt)*))?)?
+                $(allow($($allow:tt)*))?
+                $(warn($($warn:tt)*))?
+                $(deny($($deny:tt)*))?
+                $(forbid($($forbid:tt)*))?
+                $(expect($($expect:tt)*))?
+                $(deprecated $(= $deprecated:literal)? $(($($deprecated_args:tt)*))?)?
+            ] )*
+            $variant:ident = $($value:literal)? $($($path:ident)::+)? $(..= $high:literal)?
+        ),+ $(,)?
+    ) => {
+        // fast path for the rest of the variants having nothing but `#[yikes(...)]` and
+        // the usual built-in attributes, with literal or constant values, to avoid
+        // recursing once per attribute and variant, which hits the recursion limit a
+        // couple hundred variants in. a matcher can't tell `yikes` apart from any other
+        // attribute inside a repetition, only from ones it names; anything else goes
+        // the long way round below.
+        $crate::__yikes_intenum! {
+            @unknown_name $header [$($done)* $( {
+                [$( $(#[doc $(= $doc)? $(($($doc_args)*))?])?
+                    $(#[allow($($allow)*)])?
+                    $(#[warn($($warn)*)])?
+                    $(#[deny($($deny)*)])?
+                    $(#[forbid($($forbid)*)])?
+                    $(#[expect($($expect)*)])?
+                    $(#[deprecated $(= $deprecated)? $(($($deprecated_args)*))?])? )*]
+                $variant = $($value)? $($($path)::+)?;
+                [$( $($( [$key $(= $val)? $(($($args)*))?] )*)? )* $([..= $high])?]
+            } )+]
+        }
+    };
+    (
+        @variants $header:tt [$($done:tt)*] [$($attrs:tt)*] [$($opts:tt)*]
+        #[yikes($( $key:ident $(= $val:tt)? $(($($args:tt)*))? ),* $(,)?)]
+        $($rest:tt)*
+    ) => {
+        $crate::__yikes_intenum! {
+            @variants $header [$($done)*] [$($attrs)*] [$($opts)* $( [$key $(= $val)? $(($($args)*))?] )*]
+            $($rest)*
+        }
+    };
+    (
+        @variants $header:tt [$($done:tt)*] [$($attrs:tt)*] [$($opts:tt)*]
+        #[$($attr:tt)*]
+        $($rest:tt)*
+    ) => {
+        $crate::__yikes_intenum! {
+            @variants $header [$($done)*] [$($attrs)* #[$($attr)*]] [$($opts)*]
+            $($rest)*
+        }
+    };
+    (
+        @variants $header:tt [$($done:tt)*] [$($attrs:tt)*] [$($opts:tt)*]
+        $variant:ident = $low:literal ..= $high:literal $(, $($rest:tt)*)?
+    ) => {
+        // a range is kept as its start, the canonical value, with the end tucked away
+        // in an option users can't spell.
+        $crate::__yikes_intenum! {
+            @variants $header [$($done)* { [$($attrs)*] $variant = $low; [$($opts)* [..= $high]] }] [] []
+            $($($rest)*)?
+        }
+    };
+    (
+        @variants $header:tt [$($done:tt)*] [$($attrs:tt)*] [$($opts:tt)*]
+        $variant:ident = $value:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::__yikes_intenum! {
+            @variants $header [$($done)* { [$($attrs)*] $variant = $value; [$($opts)*] }] [] []
+            $($($rest)*)?
+        }
+    };
+    (@variants $header:tt [$($done:tt)*] [] []) => {
+        $crate::__yikes_intenum! { @unknown_name $header [$($done)*] }
+    };
+
+    // reject options nobody handles.
+    (@check_enum_opt [ord_by_declaration]) => {};
+    (@check_enum_opt [strict_eq]) => {};
+    (@check_enum_opt [serde_as = "int"]) => {};
+    (@check_enum_opt [serde_as = "str"]) => {};
+    (@check_enum_opt [unknown_tag = $tag:literal]) => {};
+    (@check_enum_opt [serde_as = $other:tt]) => {
+        compile_error!(concat!("unknown `serde_as` mode ", stringify!($other), "; expected \"int\" or \"str\""));
+    };
+    (@check_enum_opt [into_iter]) => {};
+    (@check_enum_opt [exhaustive]) => {};
+    (@check_enum_opt [step]) => {};
+    (@check_enum_opt [no_ord]) => {};
+    (@check_enum_opt [no_hash]) => {};
+    (@check_enum_opt [repr($($repr:tt)*)]) => {};
+    (@check_enum_opt [sorted]) => {};
+    (@check_enum_opt [contiguous]) => {};
+    (@check_enum_opt [ascii]) => {};
+    (@check_enum_opt [one_hot]) => {};
+    (@check_enum_opt [flags]) => {};
+    (@check_enum_opt [classify($($pattern:pat => $label:literal),+ $(,)?)]) => {};
+    (@check_enum_opt [from($($other:ident),+ $(,)?)]) => {};
+    (@check_enum_opt [unknown = $unknown:ident]) => {};
+    (@check_enum_opt [$key:ident $($rest:tt)*]) => {
+        compile_error!(concat!("unknown `yikes` option `", stringify!($key), "`"));
+    };
+    (@check_variant_opt [long = $long:literal]) => {};
+    (@check_variant_opt [default]) => {};
+    (@check_variant_opt [alias]) => {};
+    (@check_variant_opt [reserved]) => {};
+    (@check_variant_opt [parse_alias = $alias:literal]) => {};
+    (@check_variant_opt [display = $display:literal]) => {};
+    (@check_variant_opt [..= $high:literal]) => {};
+    (@check_variant_opt [$key:ident $($rest:tt)*]) => {
+        compile_error!(concat!("unknown `yikes` variant option `", stringify!($key), "`"));
+    };
+
+    // a variant's `parse_alias` names, as a slice.
+    // `unknown_tag`'s name, if given.
+    (@unknown_tag []) => { ::core::option::Option::None };
+    (@unknown_tag [[unknown_tag = $tag:literal] $($rest:tt)*]) => { ::core::option::Option::Some($tag) };
+    (@unknown_tag [$other:tt $($rest:tt)*]) => {
+        $crate::__yikes_intenum!(@unknown_tag [$($rest)*])
+    };
+
+    (@parse_aliases [] [$($done:literal)*]) => { &[$($done),*] };
+    (@parse_aliases [[parse_alias = $alias:literal] $($rest:tt)*] [$($done:literal)*]) => {
+        $crate::__yikes_intenum!(@parse_aliases [$($rest)*] [$($done)* $alias])
+    };
+    (@parse_aliases [$other:tt $($rest:tt)*] $done:tt) => {
+        $crate::__yikes_intenum!(@parse_aliases [$($rest)*] $done)
+    };
+
+    // a variant's `long` name.
+    (@long_name []) => { ::core::option::Option::None };
+    (@long_name [[long = $long:literal] $($rest:tt)*]) => { ::core::option::Option::Some($long) };
+    (@long_name [$other:tt $($rest:tt)*]) => { $crate::__yikes_intenum!(@long_name [$($rest)*]) };
+
+    // what `Display` shows for a variant; its `display` text, or its identifier.
+    (@display_name [] $variant:ident) => { stringify!($variant) };
+    (@display_name [[display = $display:literal] $($rest:tt)*] $variant:ident) => { $display };
+    (@display_name [$other:tt $($rest:tt)*] $variant:ident) => {
+        $crate::__yikes_intenum!(@display_name [$($rest)*] $variant)
+    };
+
+    // Default, returning the variant marked `default`; and how many are marked.
+    (@impl_default [] $name:ident $variant:ident) => {};
+    (@impl_default [[default] $($rest:tt)*] $name:ident $variant:ident) => {
+        #[allow(deprecated)]
+        impl ::core::default::Default for $name {
+            #[inline]
+            fn default() -> Self {
+                $name::$variant
             }
+        }
+    };
+    (@impl_default [$other:tt $($rest:tt)*] $name:ident $variant:ident) => {
+        $crate::__yikes_intenum! { @impl_default [$($rest)*] $name $variant }
+    };
+    (@is_reserved []) => { false };
+    (@is_reserved [[reserved] $($rest:tt)*]) => { true };
+    (@is_reserved [$other:tt $($rest:tt)*]) => { $crate::__yikes_intenum!(@is_reserved [$($rest)*]) };
+    (@is_default []) => { 0 };
+    (@is_default [[default] $($rest:tt)*]) => { 1 };
+    (@is_default [$other:tt $($rest:tt)*]) => { $crate::__yikes_intenum!(@is_default [$($rest)*]) };
 
-            // #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-            #[derive(Eq, Clone, Copy)]
-            // #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-            $( #[$enum_attr] )*
-            #[repr($ty)]
-            pub enum $name {
-                $(
-                $( #[$variant_attr] )*
-                $variant
-                ),*,
-                Unknown {
-                    value: $ty,
-                    _private: [< _ $name:snake _private >]::Sealed
+    // a variant decodes back to itself; or, for an alias, to an earlier variant. The
+    // variant it collides with is found at compile time, rather than checked for one
+    // by one, to keep the expansion linear in the number of variants.
+    (@check_round_trips $name:ident $( { $variant:ident = $value:expr; $opts:tt } )+) => {
+        $( $crate::__yikes_intenum! { @check_round_trip $opts $name $variant = $value } )+
+    };
+    (@check_round_trip [] $name:ident $variant:ident = $value:expr) => {
+        #[allow(deprecated)]
+        const _: () = {
+            // the name of the variant that this one's value decodes to instead, if any.
+            const OTHER: &str = match $name::from_repr($name::$variant.to_repr()) {
+                $name::$variant => "",
+                other => match other.name() {
+                    Some(name) => name,
+                    None => "",
+                },
+            };
+            const PARTS: [&str; 7] = [
+                concat!("`", stringify!($name), "::", stringify!($variant), "` shares its value, `"),
+                stringify!($value),
+                "`, with `",
+                stringify!($name),
+                "::",
+                OTHER,
+                "`; mark it `#[yikes(alias)]` if that's intended",
+            ];
+            const MESSAGE: [u8; $crate::__private::concat_len(&PARTS)] = $crate::__private::concat(&PARTS);
+            if !OTHER.is_empty() {
+                match ::core::str::from_utf8(&MESSAGE) {
+                    Ok(message) => panic!("{}", message),
+                    Err(_) => panic!("two variants share a value"),
                 }
             }
+        };
+    };
+    (@check_round_trip [[alias] $($rest:tt)*] $name:ident $variant:ident = $value:expr) => {
+        #[allow(deprecated)]
+        const _: () = assert!(
+            !matches!($name::from_repr($name::$variant.to_repr()), $name::$variant),
+            concat!(
+                "`", stringify!($name), "::", stringify!($variant),
+                "` is an alias, but doesn't share its value with an earlier variant"
+            )
+        );
+    };
+    (@check_round_trip [$first:tt $($rest:tt)*] $name:ident $variant:ident = $value:expr) => {
+        $crate::__yikes_intenum! { @check_round_trip [$($rest)*] $name $variant = $value }
+    };
 
-            // Debug
-            impl ::core::fmt::Debug for $name {
-                #[inline]
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    match self {
-                        $( $name::$variant => ::core::fmt::Formatter::write_str(f, stringify!($variant)) ),*,
-                        $name::Unknown{value: other, ..} => {
-                            write!(f, "Unknown({})", other)
-                        }
-                    }
-                }
+    // the values a variant decodes from, as a pattern, and the last of them; more
+    // than one only for a `$low..=$high` range. The value is passed along as a
+    // single `tt`, since matching it as an `expr` again would stop a constant from
+    // being accepted as a pattern.
+    (@pattern [] $value:tt) => { $value };
+    (@pattern [[..= $high:literal] $($rest:tt)*] $value:tt) => { $value..=$high };
+    (@pattern [$other:tt $($rest:tt)*] $value:tt) => { $crate::__yikes_intenum!(@pattern [$($rest)*] $value) };
+    (@range_end [] $value:expr) => { $value };
+    (@range_end [[..= $high:literal] $($rest:tt)*] $value:expr) => { $high };
+    (@range_end [$other:tt $($rest:tt)*] $value:expr) => { $crate::__yikes_intenum!(@range_end [$($rest)*] $value) };
+
+    // a range must fit the repr and ascend, and can't overlap any earlier variant;
+    // a single value declared after it, inside it, fails its own round trip check.
+    (@check_range [] $name:ident $ty:ident $index:path; $variant:ident = $value:expr) => {};
+    (@check_range [[..= $high:literal] $($rest:tt)*] $name:ident $ty:ident $index:path; $variant:ident = $value:expr) => {
+        #[deny(overflowing_literals)]
+        const _: $ty = $high;
+        #[allow(deprecated)]
+        const _: () = {
+            assert!(
+                $value < $high,
+                concat!("`", stringify!($name), "::", stringify!($variant), "`'s range must ascend")
+            );
+            let i = <$index>::$variant as usize;
+            let (first, last) = $name::RANGES[i];
+            let mut j = 0;
+            while j < i {
+                assert!(
+                    $name::RANGES[j].1 < first || last < $name::RANGES[j].0,
+                    concat!(
+                        "`", stringify!($name), "::", stringify!($variant),
+                        "`'s range overlaps an earlier variant"
+                    )
+                );
+                j += 1;
             }
+        };
+    };
+    (@check_range [$other:tt $($rest:tt)*] $name:ident $ty:ident $index:path; $variant:ident = $value:expr) => {
+        $crate::__yikes_intenum! { @check_range [$($rest)*] $name $ty $index; $variant = $value }
+    };
 
-            // PartialEq (Eq is derived automatically)
-            // impl ::core::marker::StructuralPartialEq for $name {}
-            impl ::core::cmp::PartialEq for $name {
-                #[inline]
-                fn eq(&self, other: &$name) -> bool {
-                    $ty::from(self).eq(&$ty::from(other))
-                }
+    // the key that equality and hashing go by; the value, unless `strict_eq`.
+    (@eq_key [] $name:ident $v:expr) => { $v.to_repr() };
+    (@eq_key [[strict_eq] $($rest:tt)*] $name:ident $v:expr) => {
+        ($v.to_repr(), $v.is_unknown())
+    };
+    (@eq_key [$other:tt $($rest:tt)*] $name:ident $v:expr) => {
+        $crate::__yikes_intenum!(@eq_key [$($rest)*] $name $v)
+    };
+
+    // the key that ordering goes by; the equality key, prefixed with the
+    // declaration index if `ord_by_declaration`.
+    (@ord_key [] $opts:tt $name:ident $v:expr) => {
+        $crate::__yikes_intenum!(@eq_key $opts $name $v)
+    };
+    (@ord_key [[ord_by_declaration] $($rest:tt)*] $opts:tt $name:ident $v:expr) => {
+        (
+            $crate::__yikes_intenum!(@decl_index $opts $name $v).unwrap_or(usize::MAX),
+            $crate::__yikes_intenum!(@eq_key $opts $name $v),
+        )
+    };
+    (@ord_key [$other:tt $($rest:tt)*] $opts:tt $name:ident $v:expr) => {
+        $crate::__yikes_intenum!(@ord_key [$($rest)*] $opts $name $v)
+    };
+
+    // PartialOrd and Ord, plus PartialOrd against the underlying integer; unless
+    // `no_ord`. `$opts` carries every option, for the ordering key.
+    (@impl_ord [] $opts:tt $name:ident $ty:ident) => {
+        impl ::core::cmp::PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> ::core::option::Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
             }
+        }
 
-            // PartialOrd, Ord
-            impl ::core::cmp::PartialOrd for $name {
-                #[inline]
-                fn partial_cmp(&self, other: &$name) -> ::core::option::Option<::core::cmp::Ordering> {
-                    Some(self.cmp(other))
-                }
+        impl ::core::cmp::Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &$name) -> ::core::cmp::Ordering {
+                $crate::__yikes_intenum!(@ord_key $opts $opts $name self)
+                    .cmp(&$crate::__yikes_intenum!(@ord_key $opts $opts $name other))
             }
+        }
 
-            impl ::core::cmp::Ord for $name {
-                #[inline]
-                fn cmp(&self, other: &$name) -> ::core::cmp::Ordering {
-                    $ty::from(self).cmp(&$ty::from(other))
-                }
+        $crate::__yikes_intenum! { @impl_ord_repr $opts $name $ty }
+    };
+    (@impl_ord [[no_ord] $($rest:tt)*] $opts:tt $name:ident $ty:ident) => {};
+    (@impl_ord [$other:tt $($rest:tt)*] $opts:tt $name:ident $ty:ident) => {
+        $crate::__yikes_intenum! { @impl_ord [$($rest)*] $opts $name $ty }
+    };
+
+    // Hash, by the equality key; unless `no_hash`.
+    (@impl_hash [] $opts:tt $name:ident) => {
+        impl ::core::hash::Hash for $name {
+            #[inline]
+            fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) -> () {
+                $crate::__yikes_intenum!(@eq_key $opts $name self).hash(state)
             }
+        }
+    };
+    (@impl_hash [[no_hash] $($rest:tt)*] $opts:tt $name:ident) => {};
+    (@impl_hash [$other:tt $($rest:tt)*] $opts:tt $name:ident) => {
+        $crate::__yikes_intenum! { @impl_hash [$($rest)*] $opts $name }
+    };
 
-            // Hash
-            impl ::core::hash::Hash for $name {
-                #[inline]
-                fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) -> () {
-                    $ty::from(self).hash(state)
-                }
+    // PartialOrd against the underlying integer, both ways round; only if ordering
+    // by value, since otherwise it couldn't agree with Ord.
+    (@impl_ord_repr [] $name:ident $ty:ident) => {
+        impl ::core::cmp::PartialOrd<$ty> for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$ty) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.to_repr().partial_cmp(other)
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<$name> for $ty {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.partial_cmp(&other.to_repr())
             }
+        }
+    };
+    (@impl_ord_repr [[ord_by_declaration] $($rest:tt)*] $name:ident $ty:ident) => {};
+    (@impl_ord_repr [$other:tt $($rest:tt)*] $name:ident $ty:ident) => {
+        $crate::__yikes_intenum! { @impl_ord_repr [$($rest)*] $name $ty }
+    };
 
-            impl ::core::convert::From<$ty> for $name {
-                fn from(value: $ty) -> Self {
-                    match value {
-                        $( $value => $name::$variant ),*,
-                        other => $name::Unknown{value: other, _private: [< _ $name:snake _private >]::Sealed}
+    // look indices up by value, so that an unknown holding a named value still
+    // compares equal to the named variant; unless `strict_eq`. Either way, an alias
+    // takes its canonical variant's index, since the two compare equal.
+    (@decl_index [] $name:ident $v:expr) => { $name::from_repr($v.to_repr()).index() };
+    (@decl_index [[strict_eq] $($rest:tt)*] $name:ident $v:expr) => {
+        if $v.is_unknown() { None } else { $name::from_repr($v.to_repr()).index() }
+    };
+    (@decl_index [$other:tt $($rest:tt)*] $name:ident $v:expr) => {
+        $crate::__yikes_intenum!(@decl_index [$($rest)*] $name $v)
+    };
+
+    // the category `classify(...)` puts a value in, if any.
+    (@classify [] $value:expr) => { ::core::option::Option::<&'static str>::None };
+    (@classify [[classify($($pattern:pat => $label:literal),+ $(,)?)] $($rest:tt)*] $value:expr) => {
+        match $value {
+            $( $pattern => ::core::option::Option::<&'static str>::Some($label), )+
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    };
+    (@classify [$other:tt $($rest:tt)*] $value:expr) => {
+        $crate::__yikes_intenum!(@classify [$($rest)*] $value)
+    };
+    (@classify_fn [$vis:vis] [] $ty:ident) => {};
+    (@classify_fn [$vis:vis] [[classify $($args:tt)*] $($rest:tt)*] $ty:ident) => {
+        /// Returns the category that `value` falls into, as given by
+        /// `#[yikes(classify(...))]`, or `None` if it isn't in any.
+        $vis const fn classify(value: $ty) -> ::core::option::Option<&'static str> {
+            $crate::__yikes_intenum!(@classify [[classify $($args)*]] value)
+        }
+    };
+    (@classify_fn [$vis:vis] [$other:tt $($rest:tt)*] $ty:ident) => {
+        $crate::__yikes_intenum! { @classify_fn [$vis] [$($rest)*] $ty }
+    };
+
+    // a schema for each named variant, made by `$named(name, value)`.
+    (@schema_named $opts:tt $name:ident $named:ident; $($variant:ident),+) => {
+        $crate::__private::alloc::vec![
+            $( $named(stringify!($variant), $crate::__yikes_intenum!(@schema_value $opts $name $variant)) ),+
+        ]
+    };
+
+    // a named variant, as JSON; its name if `serde_as = "str"`, else its value.
+    (@schema_value [] $name:ident $variant:ident) => {
+        $crate::__private::serde_json::to_value($name::$variant.to_repr()).ok()
+    };
+    (@schema_value [[serde_as = "str"] $($rest:tt)*] $name:ident $variant:ident) => {
+        ::core::option::Option::Some(::core::convert::From::from(stringify!($variant)))
+    };
+    (@schema_value [$other:tt $($rest:tt)*] $name:ident $variant:ident) => {
+        $crate::__yikes_intenum!(@schema_value [$($rest)*] $name $variant)
+    };
+
+    // the schema for unknowns, given the underlying integer's; wrapped in an object
+    // under `unknown_tag`, if there is one, with `serde_as = "str"`.
+    (@schema_unknown [] $name:ident $unknown:ident) => { $unknown };
+    (@schema_unknown [[serde_as = "str"] $($rest:tt)*] $name:ident $unknown:ident) => {
+        match $name::UNKNOWN_TAG {
+            ::core::option::Option::Some(tag) => {
+                let mut tagged = $crate::__private::schemars::schema::SchemaObject {
+                    instance_type: ::core::option::Option::Some($crate::__private::schemars::schema::InstanceType::Object.into()),
+                    ..::core::default::Default::default()
+                };
+                let object = tagged.object();
+                object.properties.insert(::core::convert::From::from(tag), $unknown.into());
+                object.required.insert(::core::convert::From::from(tag));
+                object.additional_properties = ::core::option::Option::Some($crate::__private::alloc::boxed::Box::new(false.into()));
+                tagged
+            }
+            ::core::option::Option::None => $unknown,
+        }
+    };
+    (@schema_unknown [$other:tt $($rest:tt)*] $name:ident $unknown:ident) => {
+        $crate::__yikes_intenum!(@schema_unknown [$($rest)*] $name $unknown)
+    };
+
+    // Serialize and Deserialize; through the underlying integer, unless
+    // `serde_as = "str"`.
+    (@impl_serde [] $name:ident $ty:ident) => {
+        // as the underlying integer, so unknowns round-trip.
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<__S: $crate::__private::serde::Serializer>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error> {
+                $crate::__private::serde::Serialize::serialize(&self.to_repr(), serializer)
+            }
+        }
+
+        // from the underlying integer, so any value decodes.
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<__D: $crate::__private::serde::Deserializer<'de>>(deserializer: __D) -> ::core::result::Result<Self, __D::Error> {
+                <$ty as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer).map($name::from_repr)
+            }
+        }
+    };
+    (@impl_serde [[serde_as = "str"] $($rest:tt)*] $name:ident $ty:ident) => {
+        // named variants as their names; unknowns as the underlying integer, or under
+        // `unknown_tag`, as serde would a newtype variant of that name.
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<__S: $crate::__private::serde::Serializer>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error> {
+                match (self.name(), $name::UNKNOWN_TAG) {
+                    (Some(name), _) => serializer.serialize_str(name),
+                    (None, Some(tag)) => {
+                        // the unknown variant is declared after the named ones.
+                        #[allow(clippy::cast_possible_truncation)]
+                        let index = $name::COUNT as u32;
+                        serializer.serialize_newtype_variant(stringify!($name), index, tag, &self.to_repr())
                     }
+                    (None, None) => $crate::__private::serde::Serialize::serialize(&self.to_repr(), serializer),
                 }
             }
+        }
 
-            impl ::core::convert::From<&$name> for $ty {
-                fn from(value: &$name) -> Self {
-                    match value {
-                        $( &$name::$variant => $value ),*,
-                        &$name::Unknown{value: other, ..} => other
+        // from a variant's name, or any integer that fits, bare or under `unknown_tag`.
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<__D: $crate::__private::serde::Deserializer<'de>>(deserializer: __D) -> ::core::result::Result<Self, __D::Error> {
+                use $crate::__private::serde::de::{Error, IgnoredAny, MapAccess, Unexpected};
+
+                struct Visitor;
+
+                // `unknown_tag`, as a map key; compared in place, so any string will do.
+                struct Tag(&'static str);
+
+                impl<'de> $crate::__private::serde::de::DeserializeSeed<'de> for Tag {
+                    type Value = ();
+
+                    fn deserialize<__D: $crate::__private::serde::Deserializer<'de>>(self, deserializer: __D) -> ::core::result::Result<(), __D::Error> {
+                        deserializer.deserialize_str(self)
                     }
                 }
-            }
 
-            impl ::core::convert::From<$name> for $ty {
-                fn from(value: $name) -> Self {
-                    (&value).into()
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Tag {
+                    type Value = ();
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "`{}`", self.0)
+                    }
+
+                    fn visit_str<E: Error>(self, v: &str) -> ::core::result::Result<(), E> {
+                        if v == self.0 {
+                            Ok(())
+                        } else {
+                            Err(E::invalid_value(Unexpected::Str(v), &self))
+                        }
+                    }
                 }
-            }
-        } // paste::paste!
-    }
-} // macro_rules! yikes_intenum
 
-// pub[(crate)] use yikes_intenum; // if not using `#[macro_export]`
+                impl<'de> $crate::__private::serde::de::Visitor<'de> for Visitor {
+                    type Value = $name;
 
-#[cfg(test)]
-mod tests {
-    use core::cmp::Ordering;
-    use core::hash::{BuildHasher, Hash, Hasher};
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "a {} variant name, or a {} value", stringify!($name), stringify!($ty))
+                    }
 
-    use super::*;
+                    fn visit_str<E: Error>(self, v: &str) -> ::core::result::Result<$name, E> {
+                        v.parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+                    }
 
-    yikes_intenum! {
-        pub enum TestIpProtocol(u8) {
-            Icmp = 0x01_u8,
-            Tcp = 0x06_u8,
+                    fn visit_u64<E: Error>(self, v: u64) -> ::core::result::Result<$name, E> {
+                        <$ty as ::core::convert::TryFrom<u64>>::try_from(v)
+                            .map($name::from_repr)
+                            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+                    }
+
+                    fn visit_i64<E: Error>(self, v: i64) -> ::core::result::Result<$name, E> {
+                        <$ty as ::core::convert::TryFrom<i64>>::try_from(v)
+                            .map($name::from_repr)
+                            .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+                    }
+
+                    fn visit_u128<E: Error>(self, v: u128) -> ::core::result::Result<$name, E> {
+                        <$ty as ::core::convert::TryFrom<u128>>::try_from(v)
+                            .map($name::from_repr)
+                            .map_err(|_| E::invalid_value(Unexpected::Other("128-bit integer"), &self))
+                    }
+
+                    fn visit_i128<E: Error>(self, v: i128) -> ::core::result::Result<$name, E> {
+                        <$ty as ::core::convert::TryFrom<i128>>::try_from(v)
+                            .map($name::from_repr)
+                            .map_err(|_| E::invalid_value(Unexpected::Other("128-bit integer"), &self))
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> ::core::result::Result<$name, A::Error> {
+                        let Some(tag) = $name::UNKNOWN_TAG else {
+                            return Err(A::Error::invalid_type(Unexpected::Map, &self));
+                        };
+                        if map.next_key_seed(Tag(tag))?.is_none() {
+                            return Err(A::Error::invalid_length(0, &self));
+                        }
+                        let value = map.next_value::<$ty>()?;
+                        if map.next_key::<IgnoredAny>()?.is_some() {
+                            return Err(A::Error::invalid_length(2, &self));
+                        }
+                        Ok($name::from_repr(value))
+                    }
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
         }
-    }
+    };
+    (@impl_serde [[serde_as = "int"] $($rest:tt)*] $name:ident $ty:ident) => {
+        $crate::__yikes_intenum! { @impl_serde [] $name $ty }
+    };
+    (@impl_serde [$other:tt $($rest:tt)*] $name:ident $ty:ident) => {
+        $crate::__yikes_intenum! { @impl_serde [$($rest)*] $name $ty }
+    };
 
-    #[test]
-    fn test_ipprotocol_roundtrip() {
-        for i in 0..=u8::MAX {
-            let a: TestIpProtocol = i.into();
-            let b: u8 = a.into();
-            let c: TestIpProtocol = b.into();
-            let d: u8 = c.into();
-            assert_eq!(a, c);
-            assert_eq!(c, a);
-            assert_eq!(b, d);
-            assert_eq!(d, b);
+    // IntoIterator, yielding the value once; only if `into_iter`.
+    (@impl_into_iter [] $name:ident) => {};
+    (@impl_into_iter [[into_iter] $($rest:tt)*] $name:ident) => {
+        impl ::core::iter::IntoIterator for $name {
+            type Item = $name;
+            type IntoIter = ::core::iter::Once<$name>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::once(self)
+            }
         }
-    }
+    };
+    (@impl_into_iter [$other:tt $($rest:tt)*] $name:ident) => {
+        $crate::__yikes_intenum! { @impl_into_iter [$($rest)*] $name }
+    };
 
-    #[test]
-    fn test_ipprotocol_debug() {
-        for i in 0..=u8::MAX {
-            let a: TestIpProtocol = i.into();
-            match &a {
-                TestIpProtocol::Icmp => {
-                    assert_eq!("Icmp", format!("{a:?}"));
+    // Step, stepping through the values; only if `step`. Nightly-only.
+    (@impl_step [] $name:ident $ty:ident) => {};
+    (@impl_step [[step] $($rest:tt)*] $name:ident $ty:ident) => {
+        impl ::core::iter::Step for $name {
+            fn steps_between(start: &Self, end: &Self) -> (usize, ::core::option::Option<usize>) {
+                <$ty as ::core::iter::Step>::steps_between(&start.to_repr(), &end.to_repr())
+            }
+
+            fn forward_checked(start: Self, count: usize) -> ::core::option::Option<Self> {
+                <$ty as ::core::iter::Step>::forward_checked(start.to_repr(), count).map($name::from_repr)
+            }
+
+            fn backward_checked(start: Self, count: usize) -> ::core::option::Option<Self> {
+                <$ty as ::core::iter::Step>::backward_checked(start.to_repr(), count).map($name::from_repr)
+            }
+        }
+    };
+    (@impl_step [$other:tt $($rest:tt)*] $name:ident $ty:ident) => {
+        $crate::__yikes_intenum! { @impl_step [$($rest)*] $name $ty }
+    };
+
+    // From<$other> for each enum named by `from(...)`, going through the values.
+    (@impl_from_others [] $name:ident $ty:ident) => {};
+    (@impl_from_others [[from($($other:ident),+ $(,)?)] $($rest:tt)*] $name:ident $ty:ident) => {
+        $(
+            impl ::core::convert::From<$other> for $name {
+                #[inline]
+                fn from(other: $other) -> Self {
+                    $name::from_repr(<$ty as ::core::convert::From<_>>::from(other.to_repr()))
                 }
-                TestIpProtocol::Tcp => {
-                    assert_eq!("Tcp", format!("{a:?}"));
+            }
+        )+
+        $crate::__yikes_intenum! { @impl_from_others [$($rest)*] $name $ty }
+    };
+    (@impl_from_others [$other:tt $($rest:tt)*] $name:ident $ty:ident) => {
+        $crate::__yikes_intenum! { @impl_from_others [$($rest)*] $name $ty }
+    };
+
+    // conversions to and from ASCII characters; only if `ascii`.
+    (@impl_ascii [$vis:vis] [] $name:ident $ty:ident $error:ident $to_error:ident) => {};
+    (@impl_ascii [$vis:vis] [[ascii] $($rest:tt)*] $name:ident u8 $error:ident $to_error:ident) => {
+        #[doc = concat!("The error returned when converting a non-ASCII `char` into a [`", stringify!($name), "`].")]
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        $vis struct $error {
+            /// The character that isn't ASCII.
+            $vis value: char,
+        }
+
+        impl ::core::fmt::Display for $error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{:?} is not an ASCII character, so can't be a {}", self.value, stringify!($name))
+            }
+        }
+
+        impl ::core::convert::TryFrom<char> for $name {
+            type Error = $error;
+
+            #[inline]
+            fn try_from(value: char) -> ::core::result::Result<Self, Self::Error> {
+                if value.is_ascii() {
+                    Ok($name::from_repr(value as u8))
+                } else {
+                    Err($error { value })
                 }
-                TestIpProtocol::Unknown { value, .. } => {
-                    assert_eq!(format!("Unknown({value})"), format!("{a:?}"));
+            }
+        }
+
+        #[doc = concat!("The error returned when converting a [`", stringify!($name), "`] whose value isn't an ASCII code into a `char`.")]
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        $vis struct $to_error {
+            /// The value that isn't an ASCII code.
+            $vis value: u8,
+        }
+
+        impl ::core::fmt::Display for $to_error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{} value {:#04x} is not an ASCII code", stringify!($name), self.value)
+            }
+        }
+
+        impl ::core::convert::TryFrom<$name> for char {
+            type Error = $to_error;
+
+            #[inline]
+            fn try_from(value: $name) -> ::core::result::Result<Self, Self::Error> {
+                let value = value.to_repr();
+                if value.is_ascii() {
+                    Ok(char::from(value))
+                } else {
+                    Err($to_error { value })
                 }
             }
         }
-    }
+    };
+    (@impl_ascii [$vis:vis] [[ascii] $($rest:tt)*] $name:ident $ty:ident $error:ident $to_error:ident) => {
+        ::core::compile_error!(concat!("`#[yikes(ascii)]` requires a `u8` repr, not `", stringify!($ty), "`"));
+    };
+    (@impl_ascii [$vis:vis] [$other:tt $($rest:tt)*] $name:ident $ty:ident $error:ident $to_error:ident) => {
+        $crate::__yikes_intenum! { @impl_ascii [$vis] [$($rest)*] $name $ty $error $to_error }
+    };
 
-    #[test]
-    fn test_ipprotocol_eq() {
-        for i in 0..=u8::MAX {
-            let a: TestIpProtocol = i.into();
-            let b = TestIpProtocol::Unknown {
-                value: i,
-                _private: _test_ip_protocol_private::Sealed,
-            };
-            assert!(
-                a.eq(&b),
-                "a {a:?} ({}) != b {b:?} ({})",
-                u8::from(&a),
-                u8::from(&b)
-            );
-            assert!(
-                b.eq(&a),
-                "b {b:?} ({}) != a {a:?} ({})",
-                u8::from(&b),
-                u8::from(&a)
+    // compile-time checks on the order of values; only if `sorted` or `contiguous`.
+    // each variant is compared with the one declared before it, so that a failure
+    // names the variant at fault.
+    (@check_order [] $name:ident $ty:ident $index:path; $($variant:ident = $value:expr),+) => {};
+    (@check_order [[sorted] $($rest:tt)*] $name:ident $ty:ident $index:path; $($variant:ident = $value:expr),+) => {
+        const _: () = {
+            $(
+                const _: () = {
+                    // ranges go by their ends, on either side.
+                    let i = <$index>::$variant as usize;
+                    assert!(
+                        i == 0 || $name::RANGES[i - 1].1 < $name::RANGES[i].0,
+                        concat!(
+                            "`", stringify!($name), "::", stringify!($variant),
+                            "` is out of order; `#[yikes(sorted)]` requires values to ascend in declaration order"
+                        )
+                    );
+                };
+            )+
+        };
+        $crate::__yikes_intenum! { @check_order [$($rest)*] $name $ty $index; $($variant = $value),+ }
+    };
+    (@check_order [[contiguous] $($rest:tt)*] $name:ident $ty:ident $index:path; $($variant:ident = $value:expr),+) => {
+        const _: () = {
+            $(
+                const _: () = {
+                    let i = <$index>::$variant as usize;
+                    let (last, next) = if i == 0 { (0, 0) } else { ($name::RANGES[i - 1].1, $name::RANGES[i].0) };
+                    assert!(
+                        i == 0 || (last < next && next.abs_diff(last) == 1),
+                        concat!(
+                            "`", stringify!($name), "::", stringify!($variant),
+                            "` doesn't follow on from the variant before it; `#[yikes(contiguous)]` requires ascending values with no gaps"
+                        )
+                    );
+                };
+            )+
+        };
+        $crate::__yikes_intenum! { @check_order [$($rest)*] $name $ty $index; $($variant = $value),+ }
+    };
+    (@check_order [$other:tt $($rest:tt)*] $name:ident $ty:ident $index:path; $($variant:ident = $value:expr),+) => {
+        $crate::__yikes_intenum! { @check_order [$($rest)*] $name $ty $index; $($variant = $value),+ }
+    };
+
+    // compile-time check that every value is a single bit, and a way to get at it as
+    // a mask; only if `one_hot` or `flags`.
+    (@check_one_hot [] $name:ident; $($variant:ident = $value:expr),+) => {};
+    (@check_one_hot [[one_hot] $($rest:tt)*] $name:ident; $($variant:ident = $value:expr),+) => {
+        $crate::__yikes_intenum! { @check_single_bits "one_hot" $name; $($variant),+ }
+    };
+    (@check_one_hot [[flags] $($rest:tt)*] $name:ident; $($variant:ident = $value:expr),+) => {
+        $crate::__yikes_intenum! { @check_single_bits "flags" $name; $($variant),+ }
+    };
+    (@check_one_hot [$other:tt $($rest:tt)*] $name:ident; $($variant:ident = $value:expr),+) => {
+        $crate::__yikes_intenum! { @check_one_hot [$($rest)*] $name; $($variant = $value),+ }
+    };
+    (@one_hot_fn [$vis:vis] [] $ty:ident) => {};
+    (@one_hot_fn [$vis:vis] [[one_hot] $($rest:tt)*] $ty:ident) => {
+        /// Returns the value as a bit mask. A named variant's mask has exactly one bit
+        /// set; an unknown value's is returned as is, and may have any number set.
+        $vis const fn one_hot(&self) -> $ty {
+            self.to_repr()
+        }
+    };
+    (@one_hot_fn [$vis:vis] [$other:tt $($rest:tt)*] $ty:ident) => {
+        $crate::__yikes_intenum! { @one_hot_fn [$vis] [$($rest)*] $ty }
+    };
+    (@check_single_bits $opt:literal $name:ident; $($variant:ident),+) => {
+        $(
+            #[allow(deprecated)]
+            const _: () = assert!(
+                $name::$variant.to_repr().count_ones() == 1,
+                concat!(
+                    "`", stringify!($name), "::", stringify!($variant),
+                    "` isn't a single bit; `#[yikes(", $opt, ")]` requires every value to be a power of two"
+                )
             );
+        )+
+    };
+    (@flags_fns [$vis:vis] [] $ty:ident) => {};
+    (@flags_fns [$vis:vis] [[flags] $($rest:tt)*] $ty:ident) => {
+        /// Returns the value as a bit mask; for a named variant, its single bit.
+        #[allow(clippy::wrong_self_convention)] // `&self`, like `contains_in`.
+        $vis const fn to_mask(&self) -> $ty {
+            self.to_repr()
         }
-    }
 
-    #[test]
-    fn test_ipprotocol_cmp() {
+        /// Returns whether every bit of this value is set in `mask`; for a named
+        /// variant, whether its bit is. An unknown value of 0 is never contained.
+        $vis const fn contains_in(&self, mask: $ty) -> bool {
+            let bits = self.to_repr();
+            bits != 0 && mask & bits == bits
+        }
+    };
+    (@flags_fns [$vis:vis] [$other:tt $($rest:tt)*] $ty:ident) => {
+        $crate::__yikes_intenum! { @flags_fns [$vis] [$($rest)*] $ty }
+    };
+
+    // the integer types that the repr converts into losslessly, handed on to
+    // `@$then` after `$args`.
+    (@wider $then:ident $args:tt u8) => {
+        $crate::__yikes_intenum! { @$then $args [u16 u32 u64 u128 usize i16 i32 i64 i128 isize] }
+    };
+    (@wider $then:ident $args:tt u16) => {
+        $crate::__yikes_intenum! { @$then $args [u32 u64 u128 usize i32 i64 i128] }
+    };
+    (@wider $then:ident $args:tt u32) => {
+        $crate::__yikes_intenum! { @$then $args [u64 u128 i64 i128] }
+    };
+    (@wider $then:ident $args:tt u64) => {
+        $crate::__yikes_intenum! { @$then $args [u128 i128] }
+    };
+    (@wider $then:ident $args:tt i8) => {
+        $crate::__yikes_intenum! { @$then $args [i16 i32 i64 i128 isize] }
+    };
+    (@wider $then:ident $args:tt i16) => {
+        $crate::__yikes_intenum! { @$then $args [i32 i64 i128 isize] }
+    };
+    (@wider $then:ident $args:tt i32) => {
+        $crate::__yikes_intenum! { @$then $args [i64 i128] }
+    };
+    (@wider $then:ident $args:tt i64) => {
+        $crate::__yikes_intenum! { @$then $args [i128] }
+    };
+    // nothing is wider than `u128` and `i128`, and `usize` and `isize` may be 64 bits.
+    (@wider $then:ident $args:tt $ty:ident) => {};
+
+    // From<$name> for each wider integer type.
+    (@impl_widening [$name:ident $ty:ident] [$($target:ident)+]) => {
+        $(
+            impl ::core::convert::From<$name> for $target {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    <$target as ::core::convert::From<$ty>>::from(value.to_repr())
+                }
+            }
+        )+
+    };
+
+    // `try_from_<int>` for each wider integer type, narrowing first.
+    (@try_from_wider_fns [[$vis:vis] $name:ident $ty:ident] [$($source:ident)+]) => {
+        // expanded after the `paste!` around the rest, so it needs its own.
+        $crate::__private::paste::paste! { $(
+            #[doc = concat!(
+                "Converts a `", stringify!($source), "` into the variant it names, or the unknown variant, ",
+                "or `None` if it doesn't fit a `", stringify!($ty), "`; never truncating, unlike `as`."
+            )]
+            $vis fn [< try_from_ $source >](value: $source) -> ::core::option::Option<Self> {
+                <$ty as ::core::convert::TryFrom<$source>>::try_from(value).ok().map($name::from_repr)
+            }
+        )+ }
+    };
+
+    // TryFrom<$name> for the integer types narrower than the repr.
+    (@impl_narrowing [$vis:vis] $name:ident u8 $error:ident) => {};
+    (@impl_narrowing [$vis:vis] $name:ident i8 $error:ident) => {};
+    (@impl_narrowing [$vis:vis] $name:ident u16 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name u16 $error [u8 i8] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident i16 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name i16 $error [u8 i8] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident u32 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name u32 $error [u8 u16 i8 i16] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident i32 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name i32 $error [u8 u16 i8 i16] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident u64 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name u64 $error [u8 u16 u32 i8 i16 i32] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident i64 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name i64 $error [u8 u16 u32 i8 i16 i32] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident u128 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name u128 $error [u8 u16 u32 u64 i8 i16 i32 i64] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident i128 $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name i128 $error [u8 u16 u32 u64 i8 i16 i32 i64] }
+    };
+    // `usize` and `isize` are at least 16 bits wide.
+    (@impl_narrowing [$vis:vis] $name:ident usize $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name usize $error [u8 i8] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident isize $error:ident) => {
+        $crate::__yikes_intenum! { @impl_narrowing [$vis] $name isize $error [u8 i8] }
+    };
+    (@impl_narrowing [$vis:vis] $name:ident $ty:ident $error:ident [$($target:ident)+]) => {
+        #[doc = concat!("The error returned when a [`", stringify!($name), "`]'s value doesn't fit in a narrower integer type.")]
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        $vis struct $error {
+            /// The value that didn't fit.
+            $vis value: $ty,
+        }
+
+        impl ::core::fmt::Display for $error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{} value {} out of range for the target type", stringify!($name), self.value)
+            }
+        }
+
+        $(
+            impl ::core::convert::TryFrom<$name> for $target {
+                type Error = $error;
+
+                #[inline]
+                fn try_from(value: $name) -> ::core::result::Result<Self, Self::Error> {
+                    let value = $ty::from(value);
+                    <$target as ::core::convert::TryFrom<$ty>>::try_from(value).map_err(|_| $error { value })
+                }
+            }
+        )+
+    };
+
+    // the name of the catch-all variant; `Unknown`, unless `unknown = ...`.
+    (@unknown_name [$attrs:tt $opts:tt $vis:tt $name:ident $ty:ident] $variants:tt) => {
+        $crate::__yikes_intenum! { @find_unknown $opts [$attrs $opts $vis $name $ty] $variants }
+    };
+    (@find_unknown [] [$($header:tt)*] $variants:tt) => {
+        $crate::__yikes_intenum! { @repr [$($header)* Unknown] $variants }
+    };
+    (@find_unknown [[unknown = $unknown:ident] $($rest:tt)*] [$($header:tt)*] $variants:tt) => {
+        $crate::__yikes_intenum! { @repr [$($header)* $unknown] $variants }
+    };
+    (@find_unknown [$other:tt $($rest:tt)*] $header:tt $variants:tt) => {
+        $crate::__yikes_intenum! { @find_unknown [$($rest)*] $header $variants }
+    };
+
+    // the enum's `#[repr]`; `$ty`, unless `repr(...)`.
+    (@repr [$attrs:tt $opts:tt $vis:tt $name:ident $ty:ident $unknown:ident] $variants:tt) => {
+        $crate::__yikes_intenum! { @find_repr $opts [$attrs $opts $vis $name $ty $unknown] $variants }
+    };
+    (@find_repr [] [$attrs:tt $opts:tt $vis:tt $name:ident $ty:ident $unknown:ident] $variants:tt) => {
+        $crate::__yikes_intenum! { @emit [$attrs $opts $vis $name $ty $unknown [$ty]] $variants }
+    };
+    (@find_repr [[repr($($repr:tt)*)] $($rest:tt)*] [$($header:tt)*] $variants:tt) => {
+        $crate::__yikes_intenum! { @emit [$($header)* [$($repr)*]] $variants }
+    };
+    (@find_repr [$other:tt $($rest:tt)*] $header:tt $variants:tt) => {
+        $crate::__yikes_intenum! { @find_repr [$($rest)*] $header $variants }
+    };
+
+    // the enum itself, with the unknown variant last, unless `exhaustive`.
+    (
+        @enum_decl [] [$($enum_attr:tt)*] [$($repr:tt)*] [$vis:vis] $name:ident $ty:ident $unknown:ident $sealed:path;
+        $( [$($variant_attr:tt)*] $variant:ident ),+
+    ) => {
+        // #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+        #[derive(Eq, Clone, Copy)]
+        $( $enum_attr )*
+        #[repr($($repr)*)]
+        $vis enum $name {
+            $(
+            $( $variant_attr )*
+            $variant
+            ),*,
+            $unknown {
+                value: $ty,
+                _private: $sealed
+            }
+        }
+    };
+    (
+        @enum_decl [[exhaustive] $($rest:tt)*] [$($enum_attr:tt)*] [$($repr:tt)*] [$vis:vis] $name:ident $ty:ident $unknown:ident $sealed:path;
+        $( [$($variant_attr:tt)*] $variant:ident ),+
+    ) => {
+        #[derive(Eq, Clone, Copy)]
+        $( $enum_attr )*
+        #[repr($($repr)*)]
+        $vis enum $name {
+            $(
+            $( $variant_attr )*
+            $variant
+            ),*
+        }
+
+        // with nothing to fall back on, every value must be named.
+        const _: () = assert!(
+            $name::is_exhaustive(),
+            concat!("`", stringify!($name), "` is `exhaustive`, but doesn't name every value")
+        );
+    };
+    (@enum_decl [$other:tt $($rest:tt)*] $($args:tt)*) => {
+        $crate::__yikes_intenum! { @enum_decl [$($rest)*] $($args)* }
+    };
+
+    // the methods that depend on whether there's an unknown variant; everything else
+    // goes through them.
+    (
+        @unknown_fns [] [$vis:vis] $name:ident $ty:ident $unknown:ident $sealed:path;
+        $( $variant:ident = $value:expr ),+
+    ) => {
+        /// Returns the underlying integer value; for a variant given a range,
+        /// the start of it. Usable in `const` contexts, unlike `From`.
+        $vis const fn to_repr(self) -> $ty {
+            match self {
+                $( $name::$variant => $value ),*,
+                $name::$unknown{value: other, ..} => other
+            }
+        }
+
+        // the value held by the unknown variant; `None` for a named one.
+        const fn unknown_value(&self) -> ::core::option::Option<$ty> {
+            match self {
+                $name::$unknown{value, ..} => ::core::option::Option::Some(*value),
+                _ => ::core::option::Option::None,
+            }
+        }
+
+        // the unknown variant for `value`, which must not be named.
+        const fn new_unknown(value: $ty) -> Self {
+            $name::$unknown{value, _private: $sealed}
+        }
+    };
+    (
+        @unknown_fns [[exhaustive] $($rest:tt)*] [$vis:vis] $name:ident $ty:ident $unknown:ident $sealed:path;
+        $( $variant:ident = $value:expr ),+
+    ) => {
+        /// Returns the underlying integer value; for a variant given a range,
+        /// the start of it. Usable in `const` contexts, unlike `From`.
+        $vis const fn to_repr(self) -> $ty {
+            match self {
+                $( $name::$variant => $value ),*
+            }
+        }
+
+        #[allow(clippy::unused_self)]
+        const fn unknown_value(&self) -> ::core::option::Option<$ty> {
+            ::core::option::Option::None
+        }
+
+        // never called: every value is named, as checked where the enum is declared.
+        const fn new_unknown(_value: $ty) -> Self {
+            panic!("`exhaustive` enums have no unknown variant")
+        }
+    };
+    (@unknown_fns [$other:tt $($rest:tt)*] $($args:tt)*) => {
+        $crate::__yikes_intenum! { @unknown_fns [$($rest)*] $($args)* }
+    };
+
+    (
+        @emit [[$($enum_attr:tt)*] [$($opt:tt)*] [$vis:vis] $name:ident $ty:ident $unknown:ident [$($repr:tt)*]]
+        [$( { [$($variant_attr:tt)*] $variant:ident = $value:expr; [$($variant_opt:tt)*] } )+]
+    ) => {
+        $crate::__private::paste::paste! {
+            $( $crate::__yikes_intenum! { @check_enum_opt $opt } )*
+            $( $( $crate::__yikes_intenum! { @check_variant_opt $variant_opt } )* )*
+
+            // every value must be a `$ty` in range; checked here, on its own, so that
+            // the error points at the value, even where `overflowing_literals` is
+            // allowed and it would otherwise wrap around silently.
+            $(
+                #[deny(overflowing_literals)]
+                const _: $ty = $value;
+            )+
+            $(
+                $crate::__yikes_intenum! {
+                    @check_range [$($variant_opt)*] $name $ty [< _ $name _private >]::Index;
+                    $variant = $value
+                }
+            )+
+
+            // named after the enum verbatim, not in snake case, so that distinct
+            // enums can't share a module.
+            #[allow(non_snake_case)]
+            mod [< _ $name _private >] {
+                // as visible as the enum it seals, which may be less than `pub`.
+                #[allow(unreachable_pub)]
+                #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+                pub struct Sealed;
+
+                /// Declaration indices of the named variants.
+                pub(super) enum Index {
+                    $( $variant ),*
+                }
+            }
+
+            $crate::__yikes_intenum! {
+                @enum_decl [$($opt)*] [$($enum_attr)*] [$($repr)*] [$vis] $name $ty $unknown [< _ $name _private >]::Sealed;
+                $( [$($variant_attr)*] $variant ),+
+            }
+
+            // Debug
+            // (a catch-all arm for the unknown variant never matches, if `exhaustive`.)
+            #[allow(deprecated, unreachable_patterns)]
+            impl ::core::fmt::Debug for $name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        $( $name::$variant => ::core::fmt::Formatter::write_str(f, stringify!($variant)) ),*,
+                        _ => {
+                            let other = self.to_repr();
+                            match $crate::__yikes_intenum!(@classify [$($opt)*] other) {
+                                Some(category) => write!(f, concat!(stringify!($unknown), "({}, {})"), other, category),
+                                None => write!(f, concat!(stringify!($unknown), "({})"), other),
+                            }
+                        }
+                    }
+                }
+            }
+
+            $crate::__yikes_intenum_if_arbitrary! {
+                // arbitrary::Arbitrary; through the underlying integer, so unknowns
+                // get generated too.
+                impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name {
+                    fn arbitrary(u: &mut $crate::__private::arbitrary::Unstructured<'a>) -> $crate::__private::arbitrary::Result<Self> {
+                        <$ty as $crate::__private::arbitrary::Arbitrary<'a>>::arbitrary(u).map($name::from_repr)
+                    }
+
+                    fn size_hint(depth: usize) -> (usize, ::core::option::Option<usize>) {
+                        <$ty as $crate::__private::arbitrary::Arbitrary<'a>>::size_hint(depth)
+                    }
+                }
+            }
+
+            $crate::__yikes_intenum_if_proptest! {
+                // proptest::arbitrary::Arbitrary; through the underlying integer, so
+                // unknowns get generated too.
+                impl $crate::__private::proptest::arbitrary::Arbitrary for $name {
+                    type Parameters = ();
+                    type Strategy = $crate::__private::proptest::strategy::Map<
+                        <$ty as $crate::__private::proptest::arbitrary::Arbitrary>::Strategy,
+                        fn($ty) -> $name,
+                    >;
+
+                    fn arbitrary_with((): ()) -> Self::Strategy {
+                        $crate::__private::proptest::strategy::Strategy::prop_map(
+                            $crate::__private::proptest::arbitrary::any::<$ty>(),
+                            $name::from_repr,
+                        )
+                    }
+                }
+            }
+
+            $crate::__yikes_intenum_if_clap! {
+                // clap::ValueEnum; by name, for named variants only.
+                impl $crate::__private::clap::ValueEnum for $name {
+                    fn value_variants<'a>() -> &'a [Self] {
+                        &$name::VARIANTS
+                    }
+
+                    fn to_possible_value(&self) -> ::core::option::Option<$crate::__private::clap::builder::PossibleValue> {
+                        self.name().map($crate::__private::clap::builder::PossibleValue::new)
+                    }
+                }
+            }
+
+            $crate::__yikes_intenum_if_defmt! {
+                // defmt::Format; like Debug.
+                #[allow(deprecated, unreachable_patterns)]
+                impl $crate::__private::defmt::Format for $name {
+                    fn format(&self, f: $crate::__private::defmt::Formatter<'_>) {
+                        match self {
+                            $( $name::$variant => $crate::__private::defmt::write!(f, "{=str}", stringify!($variant)) ),*,
+                            _ => $crate::__private::defmt::write!(f, "{=str}({})", stringify!($unknown), self.to_repr()),
+                        }
+                    }
+                }
+            }
+
+            // Display; like Debug for named variants, friendlier for unknowns.
+            #[allow(deprecated, unreachable_patterns)]
+            impl ::core::fmt::Display for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        $(
+                            $name::$variant => ::core::fmt::Formatter::write_str(
+                                f,
+                                $crate::__yikes_intenum!(@display_name [$($variant_opt)*] $variant),
+                            )
+                        ),*,
+                        // named after the unknown variant, in snake case; `unknown`, by
+                        // default.
+                        _ => {
+                            write!(f, concat!(stringify!([< $unknown:snake >]), " ({:#x})"), self.to_repr())
+                        }
+                    }
+                }
+            }
+
+            // LowerHex, UpperHex and Binary; of the underlying integer, for any variant.
+            impl ::core::fmt::LowerHex for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::LowerHex::fmt(&self.to_repr(), f)
+                }
+            }
+
+            impl ::core::fmt::UpperHex for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::UpperHex::fmt(&self.to_repr(), f)
+                }
+            }
+
+            impl ::core::fmt::Binary for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Binary::fmt(&self.to_repr(), f)
+                }
+            }
+
+            // PartialEq (Eq is derived automatically)
+            // impl ::core::marker::StructuralPartialEq for $name {}
+            impl ::core::cmp::PartialEq for $name {
+                #[inline]
+                fn eq(&self, other: &$name) -> bool {
+                    $crate::__yikes_intenum!(@eq_key [$($opt)*] $name self)
+                        == $crate::__yikes_intenum!(@eq_key [$($opt)*] $name other)
+                }
+            }
+
+            // PartialEq against the underlying integer, both ways round.
+            impl ::core::cmp::PartialEq<$ty> for $name {
+                #[inline]
+                fn eq(&self, other: &$ty) -> bool {
+                    self.to_repr() == *other
+                }
+            }
+
+            impl ::core::cmp::PartialEq<$name> for $ty {
+                #[inline]
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.to_repr()
+                }
+            }
+
+            $crate::__yikes_intenum! { @impl_ord [$($opt)*] [$($opt)*] $name $ty }
+
+            $crate::__yikes_intenum! { @impl_hash [$($opt)*] [$($opt)*] $name }
+
+            impl ::core::convert::From<$ty> for $name {
+                fn from(value: $ty) -> Self {
+                    $name::from_repr(value)
+                }
+            }
+
+            impl ::core::convert::From<&$ty> for $name {
+                fn from(value: &$ty) -> Self {
+                    $name::from_repr(*value)
+                }
+            }
+
+            impl ::core::convert::From<&$name> for $ty {
+                fn from(value: &$name) -> Self {
+                    value.to_repr()
+                }
+            }
+
+            impl ::core::convert::From<$name> for $ty {
+                fn from(value: $name) -> Self {
+                    (&value).into()
+                }
+            }
+
+            $crate::__yikes_intenum! { @impl_narrowing [$vis] $name $ty [< $name OverflowError >] }
+
+            $crate::__yikes_intenum! { @wider impl_widening [$name $ty] $ty }
+
+            $crate::__yikes_intenum! { @impl_into_iter [$($opt)*] $name }
+            $crate::__yikes_intenum! { @impl_step [$($opt)*] $name $ty }
+
+            $crate::__yikes_intenum! { @impl_from_others [$($opt)*] $name $ty }
+
+            $crate::__yikes_intenum! { @impl_ascii [$vis] [$($opt)*] $name $ty [< $name FromCharError >] [< $name ToCharError >] }
+
+            $( $crate::__yikes_intenum! { @impl_default [$($variant_opt)*] $name $variant } )*
+            const _: () = assert!(
+                0 $( + $crate::__yikes_intenum!(@is_default [$($variant_opt)*]) )* < 2,
+                concat!("at most one `", stringify!($name), "` variant can be marked `#[yikes(default)]`")
+            );
+
+            // every named variant must decode back to itself, unless it's an alias;
+            // this catches values shared between variants by accident, where the
+            // earlier one would always win.
+            $crate::__yikes_intenum! {
+                @check_round_trips $name
+                $( { $variant = $value; [$($variant_opt)*] } )+
+            }
+
+            // the unknown variant must hold exactly the repr, and any value must
+            // round-trip through it; checked here in case the expansion drifts.
+            const _: () = {
+                let sentinels = [0, $ty::MIN, $ty::MAX];
+                let mut i = 0;
+                while i < sentinels.len() {
+                    // a named variant's value may be anywhere in its range, so only
+                    // unknowns are sure to give back exactly what they were made from.
+                    let decoded = $name::from_repr(sentinels[i]);
+                    match decoded.unknown_value() {
+                        Some(value) => {
+                            assert!(value == sentinels[i], "unknowns must hold their value");
+                            assert!(decoded.to_repr() == sentinels[i], "values must round-trip through the enum");
+                        }
+                        None => assert!(decoded.index().is_some(), "values must decode to named or unknown variants"),
+                    }
+                    i += 1;
+                }
+            };
+
+            $crate::__yikes_intenum! {
+                @check_order [$($opt)*] $name $ty [< _ $name _private >]::Index;
+                $($variant = $value),+
+            }
+
+            $crate::__yikes_intenum! { @check_one_hot [$($opt)*] $name; $($variant = $value),+ }
+
+            // Add<$ty>; steps through the value space, wrapping around at the ends.
+            impl ::core::ops::Add<$ty> for $name {
+                type Output = $name;
+
+                #[inline]
+                fn add(self, offset: $ty) -> $name {
+                    $name::from_repr(self.to_repr().wrapping_add(offset))
+                }
+            }
+
+            // FromStr; by name, case-sensitively.
+            #[allow(deprecated)]
+            impl ::core::str::FromStr for $name {
+                type Err = [< $name ParseError >];
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    match s {
+                        $( stringify!($variant) => Ok($name::$variant), )*
+                        _ => {
+                            let mut i = 0;
+                            while i < $name::COUNT {
+                                if $name::PARSE_ALIASES[i].contains(&s) {
+                                    return Ok($name::VARIANTS[i]);
+                                }
+                                i += 1;
+                            }
+                            Err([< $name ParseError >])
+                        }
+                    }
+                }
+            }
+
+            // TryFrom<&str>; the same as FromStr.
+            impl ::core::convert::TryFrom<&str> for $name {
+                type Error = [< $name ParseError >];
+
+                #[inline]
+                fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                    s.parse()
+                }
+            }
+
+            $crate::__yikes_intenum_if_serde! {
+                $crate::__yikes_intenum! { @impl_serde [$($opt)*] $name $ty }
+            }
+
+            $crate::__yikes_intenum_if_num_enum! {
+                // num_enum::FromPrimitive, like From<$ty>.
+                impl $crate::__private::num_enum::FromPrimitive for $name {
+                    type Primitive = $ty;
+
+                    #[inline]
+                    fn from_primitive(number: $ty) -> Self {
+                        $name::from_repr(number)
+                    }
+                }
+
+                // num_enum::TryFromPrimitive; strictly, failing for unknowns, as
+                // num_enum users expect.
+                impl $crate::__private::num_enum::TryFromPrimitive for $name {
+                    type Primitive = $ty;
+                    type Error = [< $name TryFromError >];
+
+                    const NAME: &'static str = stringify!($name);
+
+                    #[inline]
+                    fn try_from_primitive(number: $ty) -> ::core::result::Result<Self, Self::Error> {
+                        $name::try_from_known(number)
+                    }
+                }
+            }
+
+            $crate::__yikes_intenum_if_schemars! {
+                // schemars::JsonSchema; any of the named variants, as they serialize, or
+                // any other value of the underlying integer.
+                #[allow(deprecated)]
+                impl $crate::__private::schemars::JsonSchema for $name {
+                    fn schema_name() -> $crate::__private::alloc::string::String {
+                        ::core::convert::From::from(stringify!($name))
+                    }
+
+                    fn json_schema(gen: &mut $crate::__private::schemars::gen::SchemaGenerator) -> $crate::__private::schemars::schema::Schema {
+                        use $crate::__private::alloc::boxed::Box;
+                        use $crate::__private::schemars::schema::{Metadata, SchemaObject, SubschemaValidation};
+
+                        let named = |name: &str, value| {
+                            SchemaObject {
+                                metadata: Some(Box::new(Metadata {
+                                    title: Some(::core::convert::From::from(name)),
+                                    ..::core::default::Default::default()
+                                })),
+                                const_value: value,
+                                ..::core::default::Default::default()
+                            }
+                            .into()
+                        };
+                        let mut unknown = <$ty as $crate::__private::schemars::JsonSchema>::json_schema(gen).into_object();
+                        unknown.metadata().description = Some(::core::convert::From::from("a value without a named variant"));
+                        let mut any_of = $crate::__yikes_intenum!(@schema_named [$($opt)*] $name named; $($variant),+);
+                        any_of.push($crate::__yikes_intenum!(@schema_unknown [$($opt)*] $name unknown).into());
+                        SchemaObject {
+                            subschemas: Some(Box::new(SubschemaValidation {
+                                any_of: Some(any_of),
+                                ..::core::default::Default::default()
+                            })),
+                            ..::core::default::Default::default()
+                        }
+                        .into()
+                    }
+                }
+            }
+
+            // TryFrom<&[u8]>; big-endian (network byte order) by default.
+            impl ::core::convert::TryFrom<&[u8]> for $name {
+                type Error = [< $name FromSliceError >];
+
+                #[inline]
+                fn try_from(bytes: &[u8]) -> ::core::result::Result<Self, Self::Error> {
+                    $name::try_from_be_slice(bytes)
+                }
+            }
+
+            // not every helper gets used, when the enum isn't public; variants may be
+            // deprecated; and catch-all arms for the unknown variant never match, if
+            // `exhaustive`.
+            #[allow(dead_code, deprecated, unreachable_patterns)]
+            impl $name {
+                /// The number of named variants, not counting the unknown variant.
+                $vis const COUNT: usize = [$( stringify!($variant) ),*].len();
+
+                /// Every named variant, in declaration order.
+                $vis const VARIANTS: [$name; $name::COUNT] = [$( $name::$variant ),*];
+
+                /// `(value, variant)` pairs for each named variant, in ascending order of
+                /// value; ready for binary searching.
+                $vis const SORTED_PAIRS: [($ty, $name); $name::COUNT] = {
+                    let mut pairs = [$( ($value, $name::$variant) ),*];
+                    // insertion sort; `const`-friendly and fine for small inputs.
+                    let mut i = 1;
+                    while i < pairs.len() {
+                        let mut j = i;
+                        while j > 0 && pairs[j - 1].0 > pairs[j].0 {
+                            let swap = pairs[j - 1];
+                            pairs[j - 1] = pairs[j];
+                            pairs[j] = swap;
+                            j -= 1;
+                        }
+                        i += 1;
+                    }
+                    pairs
+                };
+
+                /// A mask covering every bit of the underlying integer type.
+                $vis const REPR_MASK: $ty = !0;
+
+                /// The length, in bytes, of the longest named variant's name. Useful for
+                /// sizing fixed buffers that names are rendered into.
+                $vis const MAX_NAME_LEN: usize = {
+                    let names: &[&str] = &[$( stringify!($variant) ),*];
+                    let mut max = 0;
+                    let mut i = 0;
+                    while i < names.len() {
+                        if names[i].len() > max {
+                            max = names[i].len();
+                        }
+                        i += 1;
+                    }
+                    max
+                };
+
+                // each named variant's `parse_alias` names, in declaration order.
+                const PARSE_ALIASES: [&'static [&'static str]; $name::COUNT] = [$(
+                    $crate::__yikes_intenum!(@parse_aliases [$($variant_opt)*] [])
+                ),*];
+
+                // the name unknowns serialize under, with `serde_as = "str"`.
+                const UNKNOWN_TAG: ::core::option::Option<&'static str> = $crate::__yikes_intenum!(@unknown_tag [$($opt)*]);
+
+                // the first and last value each named variant decodes from, in
+                // declaration order; the same, unless it was given a range.
+                const RANGES: [($ty, $ty); $name::COUNT] = [$(
+                    ($value, $crate::__yikes_intenum!(@range_end [$($variant_opt)*] $value))
+                ),*];
+
+                // the highest value any named variant decodes from.
+                const HIGHEST: $ty = {
+                    let mut highest = $name::RANGES[0].1;
+                    let mut i = 1;
+                    while i < $name::COUNT {
+                        if $name::RANGES[i].1 > highest {
+                            highest = $name::RANGES[i].1;
+                        }
+                        i += 1;
+                    }
+                    highest
+                };
+
+                // the number of values from the lowest named one to the highest, if
+                // there are few enough for a lookup table, which is faster to decode
+                // with than a `match`; or 0.
+                #[allow(unused_comparisons, trivial_numeric_casts)] // the span may be a `u8`, or a `usize`.
+                const LOOKUP_LEN: usize = {
+                    let span = $name::HIGHEST.abs_diff($name::SORTED_PAIRS[0].0);
+                    if span <= 255 { span as usize + 1 } else { 0 }
+                };
+
+                // the named variant for each value from the lowest named one up, if any;
+                // filled in declaration order, so that aliases are skipped, and the
+                // earliest variant wins wherever ranges overlap, as in a `match`.
+                const LOOKUP: [::core::option::Option<$name>; $name::LOOKUP_LEN] = {
+                    let mut lookup = [None; $name::LOOKUP_LEN];
+                    let mut i = 0;
+                    while $name::LOOKUP_LEN > 0 && i < $name::COUNT {
+                        let (mut value, last) = $name::RANGES[i];
+                        loop {
+                            let offset = value.abs_diff($name::SORTED_PAIRS[0].0) as usize;
+                            if lookup[offset].is_none() {
+                                lookup[offset] = Some($name::VARIANTS[i]);
+                            }
+                            if value >= last {
+                                break;
+                            }
+                            value += 1;
+                        }
+                        i += 1;
+                    }
+                    lookup
+                };
+
+                /// Converts an integer into the variant it names, or the unknown variant.
+                /// Usable in `const` contexts, unlike `From`.
+                $vis const fn from_repr(value: $ty) -> Self {
+                    let (low, high) = ($name::SORTED_PAIRS[0].0, $name::HIGHEST);
+                    if !$name::LOOKUP.is_empty() && low <= value && value <= high {
+                        // within the table, so the offset is at most 255.
+                        return match $name::LOOKUP[value.abs_diff(low) as usize] {
+                            Some(known) => known,
+                            None => $name::new_unknown(value),
+                        };
+                    }
+                    // aliases never match; shared values are checked for below.
+                    #[allow(unreachable_patterns)]
+                    match value {
+                        $( $crate::__yikes_intenum!(@pattern [$($variant_opt)*] $value) => $name::$variant ),*,
+                        other => $name::new_unknown(other)
+                    }
+                }
+
+                $crate::__yikes_intenum! {
+                    @unknown_fns [$($opt)*] [$vis] $name $ty $unknown [< _ $name _private >]::Sealed;
+                    $( $variant = $value ),+
+                }
+
+                /// Returns the underlying integer value for a named variant, or `sentinel`
+                /// for an unknown one; useful for bucketing all unknowns together. The
+                /// unknown value itself is discarded.
+                $vis const fn as_repr_or_sentinel(&self, sentinel: $ty) -> $ty {
+                    if self.is_unknown() {
+                        sentinel
+                    } else {
+                        self.to_repr()
+                    }
+                }
+
+                /// Returns whether this is the unknown variant.
+                $vis const fn is_unknown(&self) -> bool {
+                    self.unknown_value().is_some()
+                }
+
+                /// Returns whether this is a named variant.
+                $vis const fn is_known(&self) -> bool {
+                    !self.is_unknown()
+                }
+
+                /// Returns the variant's name, as written in its declaration, or `None` for
+                /// an unknown value.
+                $vis const fn name(&self) -> ::core::option::Option<&'static str> {
+                    match self {
+                        $( $name::$variant => Some(stringify!($variant)) ),*,
+                        _ => None
+                    }
+                }
+
+                /// Returns the variant with the given name, compared ignoring ASCII case,
+                /// or `None` if there isn't one. Unlike `FromStr`, `"tcp"` and `"TCP"`
+                /// both find a variant named `Tcp`.
+                $vis fn from_name_ignore_case(s: &str) -> ::core::option::Option<$name> {
+                    $(
+                        if s.eq_ignore_ascii_case(stringify!($variant)) {
+                            return Some($name::$variant);
+                        }
+                    )*
+                    None
+                }
+
+                /// Returns the variant's name, or `None` for an unknown value; the same as
+                /// [`Self::name`]. There's no `AsRef<str>`, since unknowns have no name to
+                /// borrow.
+                $vis const fn as_static_str(&self) -> ::core::option::Option<&'static str> {
+                    self.name()
+                }
+
+                /// Converts an integer into the variant it names, or the unknown variant;
+                /// the same as [`Self::from_repr`].
+                $vis const fn from_int(value: $ty) -> Self {
+                    $name::from_repr(value)
+                }
+
+                /// Returns the underlying integer value; the same as [`Self::to_repr`], but
+                /// taking `&self`, like [`Self::discriminant`].
+                #[allow(clippy::wrong_self_convention)] // `&self`, to match the other accessors.
+                $vis const fn to_int(&self) -> $ty {
+                    self.to_repr()
+                }
+
+                /// Returns the declared value of a named variant, or the value held by an
+                /// unknown one; the same as [`Self::to_repr`], but taking `&self`, for
+                /// building `const` lookup tables from borrowed variants.
+                $vis const fn discriminant(&self) -> $ty {
+                    self.to_repr()
+                }
+
+                /// Returns the variant's long name, as given by `#[yikes(long = "...")]`.
+                $vis const fn long_name(&self) -> ::core::option::Option<&'static str> {
+                    match self {
+                        $( $name::$variant => $crate::__yikes_intenum!(@long_name [$($variant_opt)*]) ),*,
+                        _ => None
+                    }
+                }
+
+                /// Returns whether this is a variant marked `#[yikes(reserved)]`; never
+                /// for an unknown value.
+                $vis const fn is_reserved(&self) -> bool {
+                    match self {
+                        $( $name::$variant => $crate::__yikes_intenum!(@is_reserved [$($variant_opt)*]) ),*,
+                        _ => false
+                    }
+                }
+
+                /// Returns something that displays the variant's long name, falling back
+                /// to the `Debug` representation for variants without one. Long names
+                /// are written verbatim, without escaping.
+                $vis fn display_long(self) -> impl ::core::fmt::Display {
+                    $crate::__private::DisplayFn(move |f: &mut ::core::fmt::Formatter<'_>| {
+                        match self.long_name() {
+                            Some(long) => f.write_str(long),
+                            None => ::core::fmt::Debug::fmt(&self, f),
+                        }
+                    })
+                }
+
+                /// Returns the 0-based declaration index of a named variant, or `None`
+                /// for an unknown value.
+                $vis const fn index(&self) -> ::core::option::Option<usize> {
+                    match self {
+                        $( $name::$variant => Some([< _ $name _private >]::Index::$variant as usize) ),*,
+                        _ => None
+                    }
+                }
+
+                /// Returns an iterator over the named variants, in declaration order.
+                $vis fn iter() -> impl ::core::iter::Iterator<Item = $name> + ::core::iter::DoubleEndedIterator + ::core::iter::ExactSizeIterator {
+                    $name::VARIANTS.into_iter()
+                }
+
+                /// Returns the named variant with the lowest value.
+                $vis const fn min_known() -> $name {
+                    $name::SORTED_PAIRS[0].1
+                }
+
+                /// Returns the named variant with the highest value.
+                $vis const fn max_known() -> $name {
+                    // the last of equal values may be an alias; decode it again.
+                    $name::from_repr($name::SORTED_PAIRS[$name::COUNT - 1].0)
+                }
+
+                /// Returns the named variant with the next-higher value, or `None` if
+                /// there isn't one. Works for unknown values too.
+                $vis const fn next(&self) -> ::core::option::Option<$name> {
+                    let value = self.to_repr();
+                    let mut i = 0;
+                    while i < $name::SORTED_PAIRS.len() {
+                        if $name::SORTED_PAIRS[i].0 > value {
+                            return Some($name::SORTED_PAIRS[i].1);
+                        }
+                        i += 1;
+                    }
+                    None
+                }
+
+                /// Returns the named variant with the next-lower value, or `None` if
+                /// there isn't one. Works for unknown values too.
+                $vis const fn prev(&self) -> ::core::option::Option<$name> {
+                    let value = self.to_repr();
+                    let mut i = $name::SORTED_PAIRS.len();
+                    while i > 0 {
+                        i -= 1;
+                        if $name::SORTED_PAIRS[i].0 < value {
+                            return Some($name::from_repr($name::SORTED_PAIRS[i].0));
+                        }
+                    }
+                    None
+                }
+
+                /// Returns `self` if it's a named variant, or else the named variant whose
+                /// value is numerically closest, going by the nearer end of a range
+                /// variant's range; on a tie, the lower value wins.
+                $vis const fn closest_known(&self) -> $name {
+                    // the point of each variant's range nearest `value`; aliases share
+                    // their canonical variant's, and lose ties to it, declared earlier.
+                    const fn nearest(value: $ty, (first, last): ($ty, $ty)) -> $ty {
+                        if value < first {
+                            first
+                        } else if value > last {
+                            last
+                        } else {
+                            value
+                        }
+                    }
+                    let value = self.to_repr();
+                    let mut closest = 0;
+                    let mut i = 1;
+                    while i < $name::COUNT {
+                        let (point, best) = (nearest(value, $name::RANGES[i]), nearest(value, $name::RANGES[closest]));
+                        // `abs_diff` is unsigned, so it can't overflow for signed reprs.
+                        let (distance, best_distance) = (point.abs_diff(value), best.abs_diff(value));
+                        if distance < best_distance || (distance == best_distance && point < best) {
+                            closest = i;
+                        }
+                        i += 1;
+                    }
+                    $name::VARIANTS[closest]
+                }
+
+                /// Returns the named variant declared at 0-based index `i`, or `None` if
+                /// there are fewer than `i + 1` variants. This is the inverse of
+                /// [`Self::index`], and usable in `const` contexts.
+                $vis const fn variant_at(i: usize) -> ::core::option::Option<Self> {
+                    match i {
+                        $( i if i == [< _ $name _private >]::Index::$variant as usize => Some($name::$variant), )*
+                        _ => None,
+                    }
+                }
+
+                /// Returns the named variant declared at 0-based index `i`, or `None` if
+                /// there isn't one; the same as [`Self::variant_at`], under the name
+                /// that pairs with [`Self::index`], for keying `[T; COUNT]` arrays.
+                $vis const fn from_index(i: usize) -> ::core::option::Option<Self> {
+                    $name::variant_at(i)
+                }
+
+                $crate::__yikes_intenum! { @wider try_from_wider_fns [[$vis] $name $ty] $ty }
+
+                /// Calls the closure for this variant, `on_<variant>` for a named one (in
+                /// snake case) or `on_<unknown>` with the value, and returns its result.
+                /// Unlike a `match`, there's no catch-all, so adding a variant breaks
+                /// every call until it's handled.
+                #[allow(clippy::too_many_arguments)]
+                $vis fn match_with<T>(
+                    &self,
+                    $( [< on_ $variant:snake >]: impl ::core::ops::FnOnce() -> T, )*
+                    [< on_ $unknown:snake >]: impl ::core::ops::FnOnce($ty) -> T,
+                ) -> T {
+                    match *self {
+                        $( $name::$variant => [< on_ $variant:snake >](), )*
+                        _ => [< on_ $unknown:snake >](self.to_repr()),
+                    }
+                }
+
+                /// Counts the named variants with any values that fall within
+                /// `low..=high`.
+                $vis const fn known_count_in_range(low: $ty, high: $ty) -> usize {
+                    let mut count = 0;
+                    let mut i = 0;
+                    while i < $name::COUNT {
+                        let (first, last) = $name::RANGES[i];
+                        if low <= last && first <= high && low <= high {
+                            count += 1;
+                        }
+                        i += 1;
+                    }
+                    count
+                }
+
+                /// Returns the number of distinct integer values that decode to a named
+                /// variant; every other value decodes to the unknown variant. Saturates
+                /// at `usize::MAX`, for ranges too wide to count.
+                #[allow(trivial_numeric_casts)] // the width may already be a `u128`.
+                $vis const fn coverage() -> usize {
+                    let mut count: usize = 0;
+                    let mut i = 0;
+                    while i < $name::COUNT {
+                        let (first, last) = $name::RANGES[i];
+                        // an alias adds nothing, since its value decodes to an earlier
+                        // variant; ranges can't overlap, so nothing else is counted twice.
+                        if matches!($name::from_repr(first).index(), Some(index) if index == i) {
+                            let width = last.abs_diff(first) as u128;
+                            count = if width >= usize::MAX as u128 {
+                                usize::MAX
+                            } else {
+                                count.saturating_add(width as usize + 1)
+                            };
+                        }
+                        i += 1;
+                    }
+                    count
+                }
+
+                /// Returns whether every value of the underlying type decodes to a named
+                /// variant, so that the unknown variant is never made by decoding. Only
+                /// possible with range variants, since a variant per value would leave
+                /// no discriminant for the unknown one.
+                $vis const fn is_exhaustive() -> bool {
+                    // walk the ranges in ascending order, expecting each to start right
+                    // after the last one ended.
+                    let mut next = $ty::MIN;
+                    let mut i = 0;
+                    while i < $name::COUNT {
+                        let (first, last) = match $name::SORTED_PAIRS[i].1.index() {
+                            Some(index) => $name::RANGES[index],
+                            None => return false,
+                        };
+                        // aliases repeat the value before them.
+                        if i == 0 || first != $name::SORTED_PAIRS[i - 1].0 {
+                            if first != next {
+                                return false;
+                            }
+                            if last == $ty::MAX {
+                                return true;
+                            }
+                            next = last + 1;
+                        }
+                        i += 1;
+                    }
+                    false
+                }
+
+                /// Returns `word` with the value written into its bits starting at `shift`,
+                /// replacing whatever was there. The value takes up the underlying
+                /// integer type's full width; any bits that land past the top of the
+                /// `u32` (including all but the low 32 of a wider type) are dropped.
+                ///
+                /// # Panics
+                /// Panics if `shift` is 32 or more, even in release builds, where `<<`
+                /// would otherwise wrap the shift around.
+                #[allow(trivial_numeric_casts)] // `$ty` may already be `u32`.
+                $vis const fn pack_into(&self, word: u32, shift: u32) -> u32 {
+                    assert!(shift < 32, "`shift` must be less than 32");
+                    let bits = ::core::mem::size_of::<$ty>() * 8;
+                    let mask = if bits >= 32 { u32::MAX } else { (1 << bits) - 1 };
+                    (word & !(mask << shift)) | ((self.to_repr() as u32 & mask) << shift)
+                }
+
+                /// Returns the difference between this and `other`'s values, wrapping
+                /// around at the bounds of the underlying integer type.
+                $vis const fn repr_distance(&self, other: &Self) -> $ty {
+                    self.to_repr().wrapping_sub(other.to_repr())
+                }
+
+                /// Returns `(name, value)` pairs for each named variant, in declaration
+                /// order; handy for generating reference tables.
+                $vis fn entries() -> impl ::core::iter::Iterator<Item = (&'static str, $ty)> {
+                    [$( (stringify!($variant), $name::$variant.to_repr()) ),*].into_iter()
+                }
+
+                /// Writes a `Name = value` line for each named variant, in declaration
+                /// order, to `w`; no allocation required.
+                ///
+                /// # Errors
+                /// Returns any error from `w`.
+                $vis fn write_table<W: ::core::fmt::Write>(w: &mut W) -> ::core::fmt::Result {
+                    $( writeln!(w, "{} = {}", stringify!($variant), $name::$variant.to_repr())?; )*
+                    Ok(())
+                }
+
+                $crate::__yikes_intenum! { @one_hot_fn [$vis] [$($opt)*] $ty }
+                $crate::__yikes_intenum! { @flags_fns [$vis] [$($opt)*] $ty }
+
+                $crate::__yikes_intenum! { @classify_fn [$vis] [$($opt)*] $ty }
+
+                $crate::__yikes_intenum_if_alloc! {
+                    /// Returns [`Self::SORTED_PAIRS`] as a `Vec`.
+                    $vis fn sorted_pairs() -> $crate::__private::alloc::vec::Vec<($ty, $name)> {
+                        $name::SORTED_PAIRS.to_vec()
+                    }
+                }
+
+                $crate::__yikes_intenum_if_std! {
+                    /// Converts `value`, first passing it to `on_unknown` if it doesn't
+                    /// match a named variant. Handy for spotting missing entries.
+                    $vis fn from_repr_logged(value: $ty, on_unknown: impl FnOnce($ty)) -> Self {
+                        let decoded = $name::from(value);
+                        if decoded.is_unknown() {
+                            on_unknown(value);
+                        }
+                        decoded
+                    }
+                }
+
+                $crate::__yikes_intenum_if_proptest! {
+                    /// Returns a strategy sampling the named variants uniformly, for when
+                    /// unknowns aren't wanted.
+                    $vis fn known_strategy() -> $crate::__private::proptest::sample::Select<$name> {
+                        $crate::__private::proptest::sample::select(&$name::VARIANTS[..])
+                    }
+                }
+
+                $crate::__yikes_intenum_if_rand! {
+                    /// Returns a named variant, picked uniformly at random.
+                    $vis fn random_known<R: $crate::__private::rand::Rng + ?Sized>(rng: &mut R) -> $name {
+                        $name::VARIANTS[rng.gen_range(0..$name::COUNT)]
+                    }
+                }
+
+                $crate::__yikes_intenum_if_bytemuck! {
+                    /// Converts the underlying integer's bits into a value; the same as
+                    /// [`Self::from_repr`]. The enum itself can't be `Pod`, since its
+                    /// layout isn't the integer's, but any bits decode to some value.
+                    $vis const fn from_bits(value: $ty) -> Self {
+                        $name::from_repr(value)
+                    }
+
+                    /// Returns the underlying integer's bits; the same as [`Self::to_repr`].
+                    $vis const fn to_bits(self) -> $ty {
+                        self.to_repr()
+                    }
+
+                    /// Reinterprets `bytes` as a slice of the underlying integer, in native
+                    /// byte order, and decodes each element in turn; or returns `None` if
+                    /// `bytes` is misaligned or not a whole number of elements long.
+                    $vis fn iter_from_bytes(bytes: &[u8]) -> ::core::option::Option<impl ::core::iter::Iterator<Item = $name> + '_> {
+                        $crate::__private::bytemuck::try_cast_slice::<u8, $ty>(bytes)
+                            .ok()
+                            .map(|values| values.iter().map(|&value| $name::from_repr(value)))
+                    }
+                }
+
+                $crate::__yikes_intenum_if_zerocopy! {
+                    /// Reads the underlying integer from the start of `bytes`, in native
+                    /// byte order, and decodes it; or returns `None` if `bytes` is too
+                    /// short. Use [`Self::from_be_bytes`] and friends for wire formats with
+                    /// a fixed byte order.
+                    $vis fn read_from_prefix(bytes: &[u8]) -> ::core::option::Option<$name> {
+                        <$ty as $crate::__private::zerocopy::FromBytes>::read_from_prefix(bytes).map($name::from_repr)
+                    }
+                }
+
+                $crate::__yikes_intenum_if_serde! {
+                    /// Serializes the value as its underlying integer; for use with
+                    /// `#[serde(serialize_with = "...")]`.
+                    ///
+                    /// # Errors
+                    /// Returns any error from `serializer`.
+                    $vis fn serialize_repr<__S: $crate::__private::serde::Serializer>(
+                        value: &$name,
+                        serializer: __S,
+                    ) -> ::core::result::Result<__S::Ok, __S::Error> {
+                        $crate::__private::serde::Serialize::serialize(&value.to_repr(), serializer)
+                    }
+
+                    /// Deserializes the value from its underlying integer; for use with
+                    /// `#[serde(deserialize_with = "...")]`.
+                    ///
+                    /// # Errors
+                    /// Returns any error from `deserializer`; any integer value decodes
+                    /// successfully (possibly to the unknown variant).
+                    $vis fn deserialize_repr<'de, __D: $crate::__private::serde::Deserializer<'de>>(
+                        deserializer: __D,
+                    ) -> ::core::result::Result<$name, __D::Error> {
+                        <$ty as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                            .map($name::from_repr)
+                    }
+                }
+
+                /// Converts an integer into the variant it names, rejecting values without
+                /// one instead of producing the unknown variant.
+                ///
+                /// This can't be a `TryFrom<$ty>` impl: `From<$ty>` already provides one,
+                /// through the standard library's blanket impl, that never fails.
+                ///
+                /// # Errors
+                /// Returns an error holding `value` if no variant is named for it.
+                $vis const fn try_from_known(value: $ty) -> ::core::result::Result<Self, [< $name TryFromError >]> {
+                    let decoded = $name::from_repr(value);
+                    if decoded.is_unknown() {
+                        Err([< $name TryFromError >] { value })
+                    } else {
+                        Ok(decoded)
+                    }
+                }
+
+                /// Converts an integer into the variant it names, or `None` if there isn't
+                /// one; unlike [`Self::from_repr`], never the unknown variant.
+                $vis const fn checked_from(value: $ty) -> ::core::option::Option<Self> {
+                    // aliases never match, as in `from_repr`.
+                    #[allow(unreachable_patterns)]
+                    match value {
+                        $( $crate::__yikes_intenum!(@pattern [$($variant_opt)*] $value) => Some($name::$variant), )*
+                        _ => None,
+                    }
+                }
+
+                /// Converts any integer that fits the underlying type into the variant it
+                /// names, or the unknown variant.
+                ///
+                /// # Errors
+                /// Returns `T`'s own conversion error (for the integer types,
+                /// `TryFromIntError`) if `value` doesn't fit the underlying type. Any value
+                /// that does fit decodes successfully, so an error always means overflow.
+                $vis fn try_from_any<T: ::core::convert::TryInto<$ty>>(value: T) -> ::core::result::Result<Self, T::Error> {
+                    value.try_into().map($name::from_repr)
+                }
+
+                $crate::__yikes_intenum_if_bench! {
+                    /// Decodes each value and sums them as `u64`s (wrapping around on
+                    /// overflow; signed values are sign-extended first). Values pass through
+                    /// [`core::hint::black_box`], so that the decoding isn't optimized away;
+                    /// for benchmarking decode throughput.
+                    #[allow(trivial_numeric_casts)] // `$ty` may already be `u64`.
+                    $vis fn sum_decoded(src: &[$ty]) -> u64 {
+                        src.iter().fold(0_u64, |sum, &value| {
+                            let decoded = ::core::hint::black_box($name::from_repr(::core::hint::black_box(value)));
+                            sum.wrapping_add(decoded.to_repr() as u64)
+                        })
+                    }
+                }
+
+                $crate::__yikes_intenum_if_defmt! {
+                    /// Converts `value`, first logging a `defmt` warning if it doesn't
+                    /// match a named variant.
+                    $vis fn from_repr_defmt(value: $ty) -> Self {
+                        let decoded = $name::from_repr(value);
+                        if decoded.is_unknown() {
+                            $crate::__private::defmt::warn!("unknown {}: {}", stringify!($name), value);
+                        }
+                        decoded
+                    }
+                }
+
+                /// Converts the value into any integer type, widening or narrowing as
+                /// needed.
+                ///
+                /// # Errors
+                /// Returns `T`'s own conversion error (for the integer types,
+                /// `TryFromIntError`) if the value doesn't fit in `T`.
+                $vis fn repr_try_into<T: ::core::convert::TryFrom<$ty>>(&self) -> ::core::result::Result<T, T::Error> {
+                    T::try_from(self.to_repr())
+                }
+
+                /// Returns the underlying integer's bytes, in big-endian (network) byte
+                /// order.
+                $vis const fn to_be_bytes(self) -> [u8; ::core::mem::size_of::<$ty>()] {
+                    self.to_repr().to_be_bytes()
+                }
+
+                /// Returns the underlying integer's bytes, in little-endian byte order.
+                $vis const fn to_le_bytes(self) -> [u8; ::core::mem::size_of::<$ty>()] {
+                    self.to_repr().to_le_bytes()
+                }
+
+                /// Decodes a value from the underlying integer's bytes, in big-endian
+                /// (network) byte order.
+                $vis const fn from_be_bytes(bytes: [u8; ::core::mem::size_of::<$ty>()]) -> Self {
+                    $name::from_repr(<$ty>::from_be_bytes(bytes))
+                }
+
+                /// Decodes a value from the underlying integer's bytes, in little-endian
+                /// byte order.
+                $vis const fn from_le_bytes(bytes: [u8; ::core::mem::size_of::<$ty>()]) -> Self {
+                    $name::from_repr(<$ty>::from_le_bytes(bytes))
+                }
+
+                /// Decodes a value from a slice of exactly `size_of` the underlying
+                /// integer's bytes, in big-endian (network) byte order.
+                ///
+                /// # Errors
+                /// Returns an error if the slice has the wrong length. Any integer value
+                /// decodes successfully (possibly to the unknown variant).
+                $vis fn try_from_be_slice(bytes: &[u8]) -> ::core::result::Result<Self, [< $name FromSliceError >]> {
+                    match <[u8; ::core::mem::size_of::<$ty>()] as ::core::convert::TryFrom<&[u8]>>::try_from(bytes) {
+                        Ok(array) => Ok(<$ty>::from_be_bytes(array).into()),
+                        Err(_) => Err([< $name FromSliceError >] {
+                            expected: ::core::mem::size_of::<$ty>(),
+                            found: bytes.len(),
+                        }),
+                    }
+                }
+
+                /// Decodes a value from a slice of exactly `size_of` the underlying
+                /// integer's bytes, in little-endian byte order.
+                ///
+                /// # Errors
+                /// Returns an error if the slice has the wrong length. Any integer value
+                /// decodes successfully (possibly to the unknown variant).
+                $vis fn try_from_le_slice(bytes: &[u8]) -> ::core::result::Result<Self, [< $name FromSliceError >]> {
+                    match <[u8; ::core::mem::size_of::<$ty>()] as ::core::convert::TryFrom<&[u8]>>::try_from(bytes) {
+                        Ok(array) => Ok(<$ty>::from_le_bytes(array).into()),
+                        Err(_) => Err([< $name FromSliceError >] {
+                            expected: ::core::mem::size_of::<$ty>(),
+                            found: bytes.len(),
+                        }),
+                    }
+                }
+
+                /// Pulls `size_of` the underlying integer's bytes from `iter` and decodes
+                /// them, in big-endian order if `big_endian` and little-endian otherwise.
+                /// Returns `None` if the iterator runs out of bytes first.
+                $vis fn from_byte_iter<I: ::core::iter::Iterator<Item = u8>>(iter: &mut I, big_endian: bool) -> ::core::option::Option<Self> {
+                    let mut bytes = [0_u8; ::core::mem::size_of::<$ty>()];
+                    for byte in &mut bytes {
+                        *byte = iter.next()?;
+                    }
+                    let value = if big_endian {
+                        <$ty>::from_be_bytes(bytes)
+                    } else {
+                        <$ty>::from_le_bytes(bytes)
+                    };
+                    Some(value.into())
+                }
+            }
+
+            #[doc = concat!("The error returned when converting an integer without a named [`", stringify!($name), "`] variant, strictly.")]
+            #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+            $vis struct [< $name TryFromError >] {
+                /// The value without a named variant.
+                $vis value: $ty,
+            }
+
+            impl ::core::fmt::Display for [< $name TryFromError >] {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{} has no variant for value {}", stringify!($name), self.value)
+                }
+            }
+
+            #[doc = concat!("The error returned when parsing a string that isn't the name of a [`", stringify!($name), "`] variant.")]
+            #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+            $vis struct [< $name ParseError >];
+
+            impl ::core::fmt::Display for [< $name ParseError >] {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "not the name of a {} variant", stringify!($name))
+                }
+            }
+
+            #[doc = concat!("The error returned when decoding a [`", stringify!($name), "`] from a byte slice of the wrong length.")]
+            #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+            $vis struct [< $name FromSliceError >] {
+                /// The number of bytes required.
+                $vis expected: usize,
+                /// The number of bytes given.
+                $vis found: usize,
+            }
+
+            impl ::core::fmt::Display for [< $name FromSliceError >] {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(
+                        f,
+                        "expected {} bytes to decode {}, found {}",
+                        self.expected,
+                        stringify!($name),
+                        self.found
+                    )
+                }
+            }
+        } // $crate::__private::paste::paste!
+    };
+} // macro_rules! __yikes_intenum
+
+/// Expands to its input only if the `std` feature is enabled.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_std {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `std` feature is enabled.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_std {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_alloc {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `alloc` feature is enabled.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_alloc {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `bench` feature is enabled.
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_bench {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `bench` feature is enabled.
+#[cfg(not(feature = "bench"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_bench {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `arbitrary` feature is enabled.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_arbitrary {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `arbitrary` feature is enabled.
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_arbitrary {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `proptest` feature is enabled.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_proptest {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `proptest` feature is enabled.
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_proptest {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `rand` feature is enabled.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_rand {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `rand` feature is enabled.
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_rand {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `num_enum` feature is enabled.
+#[cfg(feature = "num_enum")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_num_enum {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `num_enum` feature is enabled.
+#[cfg(not(feature = "num_enum"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_num_enum {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `schemars` feature is enabled.
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_schemars {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `schemars` feature is enabled.
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_schemars {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `bytemuck` feature is enabled.
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_bytemuck {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `bytemuck` feature is enabled.
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_bytemuck {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `zerocopy` feature is enabled.
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_zerocopy {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `zerocopy` feature is enabled.
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_zerocopy {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `clap` feature is enabled.
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_clap {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `clap` feature is enabled.
+#[cfg(not(feature = "clap"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_clap {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `defmt` feature is enabled.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_defmt {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `defmt` feature is enabled.
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_defmt {
+    ($($item:tt)*) => {};
+}
+
+/// Expands to its input only if the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_serde {
+    ($($item:tt)*) => { $($item)* };
+}
+
+/// Expands to its input only if the `serde` feature is enabled.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __yikes_intenum_if_serde {
+    ($($item:tt)*) => {};
+}
+
+/// Re-exports for use by generated code.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub extern crate alloc;
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+    #[cfg(feature = "clap")]
+    pub use clap;
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "num_enum")]
+    pub use num_enum;
+    pub use paste;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "schemars")]
+    pub use serde_json;
+    #[cfg(feature = "zerocopy")]
+    pub use zerocopy;
+
+    /// The total length of `parts`, for sizing [`concat`]'s output.
+    pub const fn concat_len(parts: &[&str]) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < parts.len() {
+            len += parts[i].len();
+            i += 1;
+        }
+        len
+    }
+
+    /// Concatenates `parts` into exactly `N` bytes, for building messages of
+    /// compile-time panics, which can't allocate.
+    ///
+    /// # Panics
+    /// Panics if `parts` don't add up to `N` bytes.
+    pub const fn concat<const N: usize>(parts: &[&str]) -> [u8; N] {
+        assert!(
+            concat_len(parts) == N,
+            "parts must add up to the output length"
+        );
+        let mut bytes = [0; N];
+        let (mut i, mut n) = (0, 0);
+        while i < parts.len() {
+            let part = parts[i].as_bytes();
+            let mut j = 0;
+            while j < part.len() {
+                bytes[n] = part[j];
+                (j, n) = (j + 1, n + 1);
+            }
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Implements `Display` with a closure.
+    pub struct DisplayFn<F>(pub F);
+
+    impl<F: Fn(&mut core::fmt::Formatter<'_>) -> core::fmt::Result> core::fmt::Display
+        for DisplayFn<F>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            (self.0)(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use core::hash::{BuildHasher, Hash, Hasher};
+
+    use super::*;
+
+    yikes_intenum! {
+        pub enum TestIpProtocol(u8) {
+            Icmp = 0x01_u8,
+            Tcp = 0x06_u8,
+        }
+    }
+
+    yikes_intenum! {
+        /// IP protocols, with their long names.
+        pub enum TestLongProtocol(u8) {
+            /// Internet Control Message Protocol.
+            #[yikes(long = "Internet Control Message Protocol")]
+            Icmp = 0x01,
+            #[yikes(long = "Transmission Control Protocol")]
+            /// Transmission Control Protocol.
+            Tcp = 0x06,
+            /// User Datagram Protocol.
+            #[doc(alias = "datagram")]
+            Udp = 0x11,
+            #[yikes(long = "IPv6 \"no next header\"\tmarker")]
+            Ipv6NoNxt = 0x3b,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestWideCode(u32) {
+            Small = 0x0000_0001,
+            Large = 0x8000_0000,
+            Max = 0xffff_ffff,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(strict_eq)]
+        pub enum TestStrictProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(sorted, contiguous)]
+        pub enum TestOpcode(i16) {
+            Nop = -1,
+            Load = 0,
+            Store = 1,
+            Jump = 2,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(ascii)]
+        pub enum TestControl(u8) {
+            Nul = 0x00,
+            Tab = 0x09,
+            LineFeed = 0x0a,
+            Escape = 0x1b,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(one_hot)]
+        pub enum TestTcpFlag(u8) {
+            Fin = 0x01,
+            Syn = 0x02,
+            Rst = 0x04,
+            Ack = 0x10,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(from(TestIpProtocol, TestLongProtocol))]
+        pub enum TestIpProtocolV2(u16) {
+            Tcp = 0x06,
+            Udp = 0x11,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(serde_as = "str")]
+        pub enum TestNamedProtocol(u8) {
+            Icmp = 0x01,
+            #[yikes(parse_alias = "TCP", parse_alias = "Transmission")]
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(serde_as = "str", unknown_tag = "unassigned")]
+        pub enum TestTaggedProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestSingle(u8) {
+            Only = 1,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestDefaultProtocol(u8) {
+            Icmp = 0x01,
+            /// The usual.
+            #[yikes(default)]
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(unknown = Other)]
+        pub enum TestOtherProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestAliasedProtocol(u8) {
+            Icmp = 0x01,
+            Ipv4 = 0x04,
+            /// IP-in-IP, another name for `Ipv4`.
+            #[yikes(alias)]
+            IpIp = 0x04,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(one_hot)]
+        pub enum TestDeprecatedFlag(u8) {
+            Fin = 0x01,
+            #[deprecated = "retired"]
+            Ece = 0x40,
+            #[yikes(default)]
+            Cwr = 0x80,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(no_ord, no_hash)]
+        pub enum TestMinimalProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestSignedCode(i8) {
+            Min = -128,
+            Negative = -1,
+            Zero = 0,
+            Max = 127,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(repr(C, u8))]
+        pub enum TestCProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        enum TestRangedProtocol(u8) {
+            Icmp = 0x01,
+            Experimental = 0xfd..=0xfe,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(contiguous)]
+        enum TestDenseRange(u8) {
+            Low = 0..=3,
+            Four = 4,
+            High = 5..=0xff,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(exhaustive)]
+        enum TestSign(i8) {
+            Negative = -128..=-1,
+            Zero = 0,
+            Positive = 1..=127,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(sorted)]
+        enum TestRangedCode(i16) {
+            Reserved = -0x200..=-0x100,
+            Ok = 0,
+            Vendor = 0x1000..=0x10ff,
+        }
+    }
+
+    yikes_intenum! {
+        enum TestDisplayProtocol(u8) {
+            #[yikes(display = "Transmission Control Protocol")]
+            Tcp = 0x06,
+            Udp = 0x11,
+        }
+    }
+
+    yikes_intenum! {
+        enum TestReservedProtocol(u8) {
+            Tcp = 0x06,
+            #[yikes(reserved)]
+            Experimental = 0xfd,
+            #[yikes(reserved, long = "reserved")]
+            Reserved = 0xff,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(flags)]
+        enum TestFlagBit(u16) {
+            Fin = 0x001,
+            Syn = 0x002,
+            Ns = 0x100,
+        }
+    }
+
+    const TEST_TCP: u8 = 0x06;
+
+    yikes_intenum! {
+        enum TestConstProtocol(u8) {
+            Icmp = 0x01,
+            #[yikes(long = "Transmission Control Protocol")]
+            Tcp = TEST_TCP,
+            Experimental = 0xfd..=0xfe,
+        }
+    }
+
+    yikes_intenum! {
+        enum TestPrivateProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        pub(crate) enum TestCrateProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestNoTrailingComma(u8) {
+            First = 1,
+            Second = 2
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(classify(0x80..=0xfe => "reserved", 0xff => "experimental"))]
+        pub enum TestClassifiedOption(u8) {
+            End = 0x00,
+            Nop = 0x01,
+            Experimental = 0xfe,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(into_iter)]
+        pub enum TestIterProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(ord_by_declaration, strict_eq)]
+        pub enum TestStrictPriority(u8) {
+            High = 3,
+            Low = 1,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(ord_by_declaration, strict_eq)]
+        pub enum TestStrictAliasedPriority(u8) {
+            High = 3,
+            Low = 1,
+            #[yikes(alias)]
+            Lowest = 1,
+        }
+    }
+
+    yikes_intenum! {
+        #[yikes(ord_by_declaration)]
+        pub enum TestPriority(u8) {
+            High = 3,
+            Low = 1,
+            Medium = 2,
+        }
+    }
+
+    yikes_intenum! {
+        #[cfg_attr(not(feature = "serde"), derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(not(feature = "serde"), serde(try_from = "u8", into = "u8"))]
+        pub enum TestSerdeProtocol(u8) {
+            Icmp = 0x01,
+            Tcp = 0x06,
+        }
+    }
+
+    yikes_intenum! {
+        pub enum TestEtherType(u16) {
+            Ipv4 = 0x0800_u16,
+            Arp = 0x0806_u16,
+            Ipv6 = 0x86dd_u16,
+        }
+    }
+
+    #[test]
+    fn test_ipprotocol_roundtrip() {
+        for i in 0..=u8::MAX {
+            let a: TestIpProtocol = i.into();
+            let b: u8 = a.into();
+            let c: TestIpProtocol = b.into();
+            let d: u8 = c.into();
+            assert_eq!(a, c);
+            assert_eq!(c, a);
+            assert_eq!(b, d);
+            assert_eq!(d, b);
+        }
+    }
+
+    #[test]
+    fn test_ipprotocol_debug() {
+        for i in 0..=u8::MAX {
+            let a: TestIpProtocol = i.into();
+            match &a {
+                TestIpProtocol::Icmp => {
+                    assert_eq!("Icmp", format!("{a:?}"));
+                }
+                TestIpProtocol::Tcp => {
+                    assert_eq!("Tcp", format!("{a:?}"));
+                }
+                TestIpProtocol::Unknown { value, .. } => {
+                    assert_eq!(format!("Unknown({value})"), format!("{a:?}"));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ipprotocol_eq() {
+        for i in 0..=u8::MAX {
+            let a: TestIpProtocol = i.into();
+            let b = TestIpProtocol::Unknown {
+                value: i,
+                _private: _TestIpProtocol_private::Sealed,
+            };
+            assert!(
+                a.eq(&b),
+                "a {a:?} ({}) != b {b:?} ({})",
+                u8::from(&a),
+                u8::from(&b)
+            );
+            assert!(
+                b.eq(&a),
+                "b {b:?} ({}) != a {a:?} ({})",
+                u8::from(&b),
+                u8::from(&a)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ipprotocol_cmp() {
         #![allow(clippy::similar_names)]
 
-        for i in 0..u8::MAX {
-            for a in [
-                i.into(),
-                TestIpProtocol::Unknown {
-                    value: i,
-                    _private: _test_ip_protocol_private::Sealed,
-                },
-            ] {
-                for j in (i + 1)..=u8::MAX {
-                    for b in [
-                        j.into(),
-                        TestIpProtocol::Unknown {
-                            value: j,
-                            _private: _test_ip_protocol_private::Sealed,
-                        },
-                    ] {
-                        let a_cmp_b = a.cmp(&b);
-                        let a_pcmp_b = a.partial_cmp(&b);
-                        let b_cmp_a = b.cmp(&a);
-                        let b_pcmp_a = b.partial_cmp(&a);
-                        let a_int = u8::from(&a);
-                        let b_int = u8::from(&b);
+        for i in 0..u8::MAX {
+            for a in [
+                i.into(),
+                TestIpProtocol::Unknown {
+                    value: i,
+                    _private: _TestIpProtocol_private::Sealed,
+                },
+            ] {
+                for j in (i + 1)..=u8::MAX {
+                    for b in [
+                        j.into(),
+                        TestIpProtocol::Unknown {
+                            value: j,
+                            _private: _TestIpProtocol_private::Sealed,
+                        },
+                    ] {
+                        let a_cmp_b = a.cmp(&b);
+                        let a_pcmp_b = a.partial_cmp(&b);
+                        let b_cmp_a = b.cmp(&a);
+                        let b_pcmp_a = b.partial_cmp(&a);
+                        let a_int = u8::from(&a);
+                        let b_int = u8::from(&b);
+
+                        assert_eq!(
+                            a_cmp_b,
+                            Ordering::Less,
+                            "[cmp] a {a:?} ({a_int}) !< b {b:?} ({b_int})"
+                        );
+                        assert_eq!(
+                            a_pcmp_b,
+                            Some(Ordering::Less),
+                            "[pcmp] a {a:?} ({a_int}) !< b {b:?} ({b_int})"
+                        );
+                        assert_eq!(
+                            b_cmp_a,
+                            Ordering::Greater,
+                            "[cmp] b {b:?} ({b_int}) !> a {a:?} ({a_int})"
+                        );
+                        assert_eq!(
+                            b_pcmp_a,
+                            Some(Ordering::Greater),
+                            "[pcmp] b {b:?} ({b_int}) !> a {a:?} ({a_int})"
+                        );
+
+                        // extra checks specifically for invariants that should hold
+                        // for correct implementations of `Ord` and `PartialOrd`.
+                        // (just in case the above is ever modified carelessly).
+
+                        // check that `cmp` and `partial_cmp` agree.
+                        assert_eq!(a_pcmp_b, Some(a_cmp_b), "a cmp b != a pcmp b");
+                        assert_eq!(b_pcmp_a, Some(b_cmp_a), "b cmp a != b pcmp a!");
+
+                        // check reversing args also reverses the `cmp` result.
+                        assert_eq!(a_cmp_b, b_cmp_a.reverse());
+                        assert_eq!(b_cmp_a, a_cmp_b.reverse());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ipprotocol_hash() {
+        for i in 0..u8::MAX {
+            let a: TestIpProtocol = i.into();
+            let b = TestIpProtocol::Unknown {
+                value: i,
+                _private: _TestIpProtocol_private::Sealed,
+            };
+
+            #[allow(unused_qualifications, clippy::type_complexity)]
+            let hashers: Vec<(
+                &str,
+                Box<dyn core::hash::Hasher>,
+                Box<dyn core::hash::Hasher>,
+            )> = vec![
+                (
+                    "std::collections::hash_map::DefaultHasher",
+                    Box::new(std::collections::hash_map::DefaultHasher::new()) as _,
+                    Box::new(std::collections::hash_map::DefaultHasher::new()) as _,
+                ),
+                (
+                    "FnvHasher",
+                    Box::new(fnv::FnvBuildHasher::default().build_hasher()) as _,
+                    Box::new(fnv::FnvBuildHasher::default().build_hasher()) as _,
+                ),
+            ];
+
+            for (hasher_kind, mut hasher_a, mut hasher_b) in hashers {
+                #[allow(clippy::unreadable_literal)]
+                let noises: &[Option<u64>] = &[
+                    None,
+                    Some(18223650421099562965_u64),
+                    Some(579348513557276885_u64),
+                    Some(6018745257231369041_u64),
+                    Some(4974397919804797078_u64),
+                    Some(6574880736321336287_u64),
+                    Some(8334883869055102477_u64),
+                    Some(8077341428061256032_u64),
+                    Some(8702568753483328048_u64),
+                ];
+                for noise in noises {
+                    let assert_hashers_match =
+                        |hasher_a: &mut dyn Hasher, hasher_b: &mut dyn Hasher| {
+                            let ha = &mut hasher_a.finish();
+                            let hb = &mut hasher_b.finish();
+                            assert_eq!(
+                                ha,
+                                hb,
+                                "[kind={hasher_kind}, noise={noise:?}] hash{{a {a:?} ({})}} {ha} != hash{{b {b:?} ({})}} {hb}",
+                                u8::from(&a),
+                                u8::from(&b),
+                            );
+                        };
+
+                    if let Some(noise) = noise {
+                        noise.hash(&mut hasher_a);
+                        noise.hash(&mut hasher_b);
+                        assert_hashers_match(&mut hasher_a, &mut hasher_b);
+                    }
+                    a.hash(&mut hasher_a);
+                    b.hash(&mut hasher_b);
+                    assert_hashers_match(&mut hasher_a, &mut hasher_b);
+                }
+                assert_eq!(
+                    a,
+                    b,
+                    "[kind={hasher_kind}] hash{{a}} == hash{{b}} must imply a == b, but: a {a:?} ({}) != b {b:?} ({})",
+                    u8::from(&a),
+                    u8::from(&b)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ipprotocol_hash_different() {
+        for i in 0..u8::MAX {
+            for a in [
+                i.into(),
+                TestIpProtocol::Unknown {
+                    value: i,
+                    _private: _TestIpProtocol_private::Sealed,
+                },
+            ] {
+                for j in 0..=u8::MAX {
+                    if i == j {
+                        continue;
+                    }
+                    for b in [
+                        j.into(),
+                        TestIpProtocol::Unknown {
+                            value: j,
+                            _private: _TestIpProtocol_private::Sealed,
+                        },
+                    ] {
+                        let mut hasher_a =
+                            Box::new(std::collections::hash_map::DefaultHasher::new());
+                        let mut hasher_b =
+                            Box::new(std::collections::hash_map::DefaultHasher::new());
+                        a.hash(&mut hasher_a);
+                        b.hash(&mut hasher_b);
+                        let ha = hasher_a.finish();
+                        let hb = hasher_b.finish();
+                        // a != b almost surely implies hash{{a}} != hash{{b}}.
+                        assert_ne!(ha, hb, "Different values yielded same hashes: hash{{a {a:?} ({int_a})}} ({ha}) == hash{{b {b:?} ({int_b})}} ({hb})", int_a=u8::from(&a), ha=ha, int_b=u8::from(&b), hb=hb);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates the equivalents of `test_ipprotocol_hash` and
+    /// `test_ipprotocol_hash_different` for an enum whose value range is too wide to
+    /// test exhaustively, checking `$samples` and their neighbours instead. Equal
+    /// values are checked with FNV as well as `DefaultHasher`: FNV mixes in one byte at
+    /// a time, so it's the likelier of the two to notice part of a wide value going
+    /// unhashed.
+    macro_rules! wide_hash_tests {
+        ($same:ident, $different:ident, $name:ident, $ty:ident, $private:ident, $samples:expr) => {
+            #[test]
+            fn $same() {
+                for i in wide_hash_tests!(@values $ty, $samples) {
+                    let a = $name::from(i);
+                    let b = $name::Unknown {
+                        value: i,
+                        _private: $private::Sealed,
+                    };
+                    assert_eq!(a, b);
+
+                    #[allow(unused_qualifications)]
+                    let hashes = |v: &$name| {
+                        let mut default = std::collections::hash_map::DefaultHasher::new();
+                        let mut fnv = fnv::FnvBuildHasher::default().build_hasher();
+                        v.hash(&mut default);
+                        v.hash(&mut fnv);
+                        (default.finish(), fnv.finish())
+                    };
+                    assert_eq!(hashes(&a), hashes(&b), "hash{{{a:?}}} != hash{{{b:?}}}");
+                }
+            }
+
+            #[test]
+            fn $different() {
+                let values = wide_hash_tests!(@values $ty, $samples);
+                for &i in &values {
+                    for &j in &values {
+                        if i == j {
+                            continue;
+                        }
+                        let hash = |v: &$name| {
+                            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                            v.hash(&mut hasher);
+                            hasher.finish()
+                        };
+                        // a != b almost surely implies hash{{a}} != hash{{b}}.
+                        assert_ne!(hash(&$name::from(i)), hash(&$name::from(j)), "{i} and {j} hash the same");
+                    }
+                }
+            }
+        };
+        // each sample, with its neighbours on either side.
+        (@values $ty:ident, $samples:expr) => {{
+            let mut values: Vec<$ty> = $samples
+                .iter()
+                .flat_map(|&v: &$ty| [v.wrapping_sub(1), v, v.wrapping_add(1)])
+                .collect();
+            values.sort_unstable();
+            values.dedup();
+            values
+        }};
+    }
+
+    wide_hash_tests!(
+        test_ethertype_hash,
+        test_ethertype_hash_different,
+        TestEtherType,
+        u16,
+        _TestEtherType_private,
+        [0x0000, 0x00ff, 0x0100, 0x0800, 0x0806, 0x86dd, 0xff00, 0xffff]
+    );
+
+    wide_hash_tests!(
+        test_widecode_hash,
+        test_widecode_hash_different,
+        TestWideCode,
+        u32,
+        _TestWideCode_private,
+        [
+            0x0000_0000,
+            0x0000_0001,
+            0x0000_ff00,
+            0x0001_0000,
+            0x00ff_0000,
+            0x8000_0000,
+            0xffff_0000,
+            0xffff_ffff,
+        ]
+    );
+
+    #[test]
+    fn test_ipprotocol_try_from_slice() {
+        for i in 0..=u8::MAX {
+            let a = TestIpProtocol::try_from(&[i][..]);
+            assert_eq!(a, Ok(TestIpProtocol::from(i)));
+            assert_eq!(a, TestIpProtocol::try_from_le_slice(&[i]));
+        }
+        for bytes in [&[][..], &[0x06, 0x06][..]] {
+            assert_eq!(
+                TestIpProtocol::try_from(bytes),
+                Err(TestIpProtocolFromSliceError {
+                    expected: 1,
+                    found: bytes.len()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_ethertype_try_from_slice() {
+        assert_eq!(
+            TestEtherType::try_from(&[0x86, 0xdd][..]),
+            Ok(TestEtherType::Ipv6)
+        );
+        assert_eq!(
+            TestEtherType::try_from_le_slice(&[0xdd, 0x86]),
+            Ok(TestEtherType::Ipv6)
+        );
+        assert_eq!(
+            TestEtherType::try_from_be_slice(&[0x12, 0x34]),
+            Ok(TestEtherType::from(0x1234))
+        );
+        let Err(err) = TestEtherType::try_from(&[0x08][..]) else {
+            panic!("a 1-byte slice must not decode a u16-based enum");
+        };
+        assert_eq!(err.expected, 2);
+        assert_eq!(err.found, 1);
+        assert_eq!(
+            format!("{err}"),
+            "expected 2 bytes to decode TestEtherType, found 1"
+        );
+    }
+
+    #[test]
+    fn test_max_name_len() {
+        assert_eq!(TestIpProtocol::MAX_NAME_LEN, "Icmp".len());
+        assert_eq!(TestEtherType::MAX_NAME_LEN, "Ipv4".len());
+        let buf = [0_u8; TestIpProtocol::MAX_NAME_LEN];
+        assert_eq!(buf.len(), 4);
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(TestIpProtocol::Icmp.index(), Some(0));
+        assert_eq!(TestIpProtocol::Tcp.index(), Some(1));
+        assert_eq!(TestIpProtocol::from(0x11).index(), None);
+        assert_eq!(TestPriority::Medium.index(), Some(2));
+    }
+
+    #[test]
+    fn test_priority_ord_by_declaration() {
+        let mut values: Vec<TestPriority> = (0..=4).map(TestPriority::from).collect();
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                TestPriority::High,
+                TestPriority::Low,
+                TestPriority::Medium,
+                TestPriority::from(0),
+                TestPriority::from(4),
+            ]
+        );
+
+        // an unknown holding a named value must still compare equal to it.
+        let unknown_high = TestPriority::Unknown {
+            value: 3,
+            _private: _TestPriority_private::Sealed,
+        };
+        assert_eq!(unknown_high.cmp(&TestPriority::High), Ordering::Equal);
+        assert_eq!(unknown_high.cmp(&TestPriority::Low), Ordering::Less);
+
+        let set: std::collections::BTreeSet<TestPriority> = [
+            TestPriority::from(9),
+            TestPriority::Medium,
+            TestPriority::High,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            [
+                TestPriority::High,
+                TestPriority::Medium,
+                TestPriority::from(9)
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ipprotocol_from_repr_logged() {
+        let mut unknowns = Vec::new();
+        for i in 0..=u8::MAX {
+            let a = TestIpProtocol::from_repr_logged(i, |value| unknowns.push(value));
+            assert_eq!(a, TestIpProtocol::from(i));
+        }
+        assert_eq!(unknowns.len(), 254);
+        assert!(!unknowns.contains(&0x01));
+        assert!(!unknowns.contains(&0x06));
+    }
+
+    #[test]
+    fn test_serde_try_from_into() -> Result<(), serde_json::Error> {
+        for i in 0..=u8::MAX {
+            let a = TestSerdeProtocol::from(i);
+            let json = serde_json::to_string(&a)?;
+            assert_eq!(json, i.to_string());
+            let b: TestSerdeProtocol = serde_json::from_str(&json)?;
+            assert_eq!(a, b);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ethertype_try_into_narrower() {
+        assert_eq!(u8::try_from(TestEtherType::from(0x00ff)), Ok(0xff_u8));
+        assert_eq!(i8::try_from(TestEtherType::from(0x007f)), Ok(0x7f_i8));
+        assert_eq!(
+            u8::try_from(TestEtherType::Ipv4),
+            Err(TestEtherTypeOverflowError { value: 0x0800 })
+        );
+        assert_eq!(
+            i8::try_from(TestEtherType::from(0x0080)),
+            Err(TestEtherTypeOverflowError { value: 0x0080 })
+        );
+    }
+
+    #[test]
+    fn test_ipprotocol_const_repr() {
+        const TCP: TestIpProtocol = TestIpProtocol::from_repr(0x06);
+        const TCP_REPR: u8 = TCP.to_repr();
+        const UNKNOWN_REPR: u8 = TestIpProtocol::from_repr(0x11).to_repr();
+        assert_eq!(TCP, TestIpProtocol::Tcp);
+        assert_eq!(TCP_REPR, 0x06);
+        assert_eq!(UNKNOWN_REPR, 0x11);
+        for i in 0..=u8::MAX {
+            assert_eq!(TestIpProtocol::from_repr(i), TestIpProtocol::from(i));
+            assert_eq!(TestIpProtocol::from_repr(i).to_repr(), i);
+        }
+    }
+
+    #[test]
+    fn test_long_name() {
+        assert_eq!(
+            TestLongProtocol::Tcp.long_name(),
+            Some("Transmission Control Protocol")
+        );
+        assert_eq!(TestLongProtocol::Udp.long_name(), None);
+        assert_eq!(TestLongProtocol::from(0x2f).long_name(), None);
+        assert_eq!(
+            format!("{}", TestLongProtocol::Icmp.display_long()),
+            "Internet Control Message Protocol"
+        );
+        assert_eq!(format!("{}", TestLongProtocol::Udp.display_long()), "Udp");
+        assert_eq!(
+            format!("{}", TestLongProtocol::from(0x2f).display_long()),
+            "Unknown(47)"
+        );
+    }
+
+    #[test]
+    fn test_ethertype_from_byte_iter() {
+        let mut iter = [0x86, 0xdd, 0x08, 0x00, 0x06].into_iter();
+        assert_eq!(
+            TestEtherType::from_byte_iter(&mut iter, true),
+            Some(TestEtherType::Ipv6)
+        );
+        assert_eq!(
+            TestEtherType::from_byte_iter(&mut iter, false),
+            Some(TestEtherType::from(0x0008))
+        );
+        assert_eq!(TestEtherType::from_byte_iter(&mut iter, true), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = core::iter::once(0x06);
+        assert_eq!(
+            TestIpProtocol::from_byte_iter(&mut iter, false),
+            Some(TestIpProtocol::Tcp)
+        );
+    }
+
+    #[test]
+    fn test_sorted_pairs() {
+        assert_eq!(TestPriority::COUNT, 3);
+        assert_eq!(
+            TestPriority::SORTED_PAIRS,
+            [
+                (1, TestPriority::Low),
+                (2, TestPriority::Medium),
+                (3, TestPriority::High),
+            ]
+        );
+        assert_eq!(
+            TestEtherType::SORTED_PAIRS.binary_search_by_key(&0x0806, |&(value, _)| value),
+            Ok(1)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sorted_pairs_vec() {
+        assert_eq!(TestPriority::sorted_pairs(), TestPriority::SORTED_PAIRS);
+    }
+
+    #[test]
+    fn test_serde_flatten() -> Result<(), serde_json::Error> {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Header {
+            protocol: TestSerdeProtocol,
+        }
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Packet {
+            len: u16,
+            #[serde(flatten)]
+            header: Header,
+        }
+
+        for protocol in [0x06, 0x11, u8::MAX].map(TestSerdeProtocol::from) {
+            let packet = Packet {
+                len: 20,
+                header: Header { protocol },
+            };
+            let json = serde_json::to_string(&packet)?;
+            assert_eq!(
+                json,
+                format!(r#"{{"len":20,"protocol":{}}}"#, u8::from(protocol))
+            );
+            assert_eq!(serde_json::from_str::<Packet>(&json)?, packet);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_eq() {
+        let hash = |v: &dyn Fn(&mut std::collections::hash_map::DefaultHasher)| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            v(&mut hasher);
+            hasher.finish()
+        };
+
+        let unknown_tcp = TestStrictProtocol::Unknown {
+            value: 0x06,
+            _private: _TestStrictProtocol_private::Sealed,
+        };
+        assert_ne!(TestStrictProtocol::Tcp, unknown_tcp);
+        assert_eq!(unknown_tcp, unknown_tcp);
+        assert_eq!(TestStrictProtocol::from(0x06), TestStrictProtocol::Tcp);
+        assert_eq!(
+            TestStrictProtocol::from(0x07),
+            TestStrictProtocol::from(0x07)
+        );
+        assert_eq!(TestStrictProtocol::Tcp.cmp(&unknown_tcp), Ordering::Less);
+        assert_eq!(
+            unknown_tcp.cmp(&TestStrictProtocol::from(0x07)),
+            Ordering::Less
+        );
+        assert_ne!(
+            hash(&|h| TestStrictProtocol::Tcp.hash(h)),
+            hash(&|h| unknown_tcp.hash(h))
+        );
+        assert_eq!(
+            hash(&|h| TestStrictProtocol::from(0x06).hash(h)),
+            hash(&|h| TestStrictProtocol::Tcp.hash(h))
+        );
+
+        let unknown_high = TestStrictPriority::Unknown {
+            value: 3,
+            _private: _TestStrictPriority_private::Sealed,
+        };
+        assert_ne!(TestStrictPriority::High, unknown_high);
+        assert_eq!(TestStrictPriority::High.cmp(&unknown_high), Ordering::Less);
+        assert_eq!(TestStrictPriority::Low.cmp(&unknown_high), Ordering::Less);
+        assert_eq!(
+            unknown_high.cmp(&TestStrictPriority::from(4)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_repr_distance() {
+        assert_eq!(TestEtherType::Arp.repr_distance(&TestEtherType::Ipv4), 6);
+        assert_eq!(
+            TestEtherType::Ipv4.repr_distance(&TestEtherType::Arp),
+            0xfffa
+        );
+        assert_eq!(TestEtherType::Ipv4.repr_distance(&TestEtherType::Ipv4), 0);
+        assert_eq!(
+            TestEtherType::from(0x0801).repr_distance(&TestEtherType::Ipv4),
+            1
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_repr_functions() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Header {
+            #[serde(
+                serialize_with = "TestIpProtocol::serialize_repr",
+                deserialize_with = "TestIpProtocol::deserialize_repr"
+            )]
+            protocol: TestIpProtocol,
+        }
+
+        for (header, json) in [
+            (
+                Header {
+                    protocol: TestIpProtocol::Tcp,
+                },
+                r#"{"protocol":6}"#,
+            ),
+            (
+                Header {
+                    protocol: TestIpProtocol::from(0x11),
+                },
+                r#"{"protocol":17}"#,
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&header).ok().as_deref(), Some(json));
+            assert_eq!(serde_json::from_str::<Header>(json).ok(), Some(header));
+        }
+    }
+
+    #[test]
+    fn test_known_count_in_range() {
+        const ARP_AND_IPV4: usize = TestEtherType::known_count_in_range(0x0800, 0x0806);
+        assert_eq!(ARP_AND_IPV4, 2);
+        assert_eq!(TestEtherType::known_count_in_range(0x0000, 0xffff), 3);
+        assert_eq!(TestEtherType::known_count_in_range(0x0801, 0x0805), 0);
+        assert_eq!(TestEtherType::known_count_in_range(0x86dd, 0x86dd), 1);
+        assert_eq!(TestEtherType::known_count_in_range(0xffff, 0x0000), 0);
+    }
+
+    #[test]
+    fn test_write_table() {
+        struct Buffer {
+            bytes: [u8; 64],
+            len: usize,
+        }
+
+        impl core::fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.len + s.len();
+                self.bytes
+                    .get_mut(self.len..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.len = end;
+                Ok(())
+            }
+        }
+
+        let mut buffer = Buffer {
+            bytes: [0; 64],
+            len: 0,
+        };
+        assert!(TestEtherType::write_table(&mut buffer).is_ok());
+        assert_eq!(
+            core::str::from_utf8(&buffer.bytes[..buffer.len]),
+            Ok("Ipv4 = 2048\nArp = 2054\nIpv6 = 34525\n")
+        );
+
+        let mut short = Buffer {
+            bytes: [0; 64],
+            len: 60,
+        };
+        assert!(TestEtherType::write_table(&mut short).is_err());
+    }
+
+    #[test]
+    fn test_from_repr_ref() {
+        let values: &[u32] = &[0x0000_0001, 0x8000_0000, 0x1234_5678];
+        for value in values {
+            assert_eq!(TestWideCode::from(value), TestWideCode::from(*value));
+            assert_eq!(u32::from(TestWideCode::from(value)), *value);
+        }
+        assert_eq!(TestWideCode::from(&0xffff_ffff), TestWideCode::Max);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        fn collect<I: IntoIterator<Item = TestIterProtocol>>(values: I) -> Vec<TestIterProtocol> {
+            values.into_iter().collect()
+        }
+
+        assert_eq!(collect(TestIterProtocol::Tcp), [TestIterProtocol::Tcp]);
+        assert_eq!(
+            collect(TestIterProtocol::from(0x11)),
+            [TestIterProtocol::from(0x11)]
+        );
+        assert_eq!(
+            collect([TestIterProtocol::Icmp, TestIterProtocol::Tcp]),
+            [TestIterProtocol::Icmp, TestIterProtocol::Tcp]
+        );
+    }
+
+    #[test]
+    fn test_try_from_any() {
+        assert_eq!(
+            TestEtherType::try_from_any(0x0806_u64),
+            Ok(TestEtherType::Arp)
+        );
+        assert_eq!(
+            TestEtherType::try_from_any(0x0806_i32),
+            Ok(TestEtherType::Arp)
+        );
+        assert_eq!(
+            TestEtherType::try_from_any(0x0801_u16),
+            Ok(TestEtherType::from(0x0801))
+        );
+        assert_eq!(
+            TestEtherType::try_from_any(6_u8),
+            Ok(TestEtherType::from(6))
+        );
+        assert!(TestEtherType::try_from_any(0x1_0000_u32).is_err());
+        assert!(TestEtherType::try_from_any(-1_i8).is_err());
+        assert_eq!(
+            TestIpProtocol::try_from_any(6_usize),
+            Ok(TestIpProtocol::Tcp)
+        );
+        assert!(TestIpProtocol::try_from_any(256_usize).is_err());
+    }
+
+    #[test]
+    fn test_sorted_contiguous() {
+        assert_eq!(
+            TestOpcode::SORTED_PAIRS.map(|(_, v)| v.index()),
+            [Some(0), Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(TestOpcode::from(2), TestOpcode::Jump);
+    }
+
+    #[test]
+    fn test_long_name_debug_display_contract() {
+        assert_eq!(format!("{:?}", TestLongProtocol::Ipv6NoNxt), "Ipv6NoNxt");
+        assert_eq!(
+            format!("{}", TestLongProtocol::Ipv6NoNxt.display_long()),
+            "IPv6 \"no next header\"\tmarker"
+        );
+        assert_eq!(format!("{:?}", TestLongProtocol::Tcp), "Tcp");
+        assert_eq!(
+            format!("{}", TestLongProtocol::Tcp.display_long()),
+            "Transmission Control Protocol"
+        );
+    }
+
+    #[test]
+    fn test_coverage() {
+        const COVERAGE: usize = TestEtherType::coverage();
+        assert_eq!(COVERAGE, 3);
+        assert_eq!(TestIpProtocol::coverage(), 2);
+        assert_eq!(
+            TestOpcode::coverage(),
+            (-1..=2)
+                .filter(|&i| TestOpcode::from(i).index().is_some())
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_ascii_try_from_char() {
+        assert_eq!(TestControl::try_from('\t'), Ok(TestControl::Tab));
+        assert_eq!(TestControl::try_from('\x1b'), Ok(TestControl::Escape));
+        assert_eq!(TestControl::try_from('A'), Ok(TestControl::from(0x41)));
+        assert_eq!(
+            TestControl::try_from('\u{e9}'),
+            Err(TestControlFromCharError { value: '\u{e9}' })
+        );
+        assert_eq!(
+            TestControl::try_from('\u{1f600}'),
+            Err(TestControlFromCharError { value: '\u{1f600}' })
+        );
+    }
+
+    #[test]
+    fn test_one_hot() {
+        let flags = 0x12_u8;
+        let set: Vec<TestTcpFlag> = TestTcpFlag::SORTED_PAIRS
+            .iter()
+            .map(|&(_, flag)| flag)
+            .filter(|flag| flags & flag.one_hot() != 0)
+            .collect();
+        assert_eq!(set, [TestTcpFlag::Syn, TestTcpFlag::Ack]);
+        assert_eq!(TestTcpFlag::from(0x0c).one_hot(), 0x0c);
+    }
+
+    #[test]
+    fn test_from_other_enum() {
+        assert_eq!(
+            TestIpProtocolV2::from(TestIpProtocol::Tcp),
+            TestIpProtocolV2::Tcp
+        );
+        assert_eq!(
+            TestIpProtocolV2::from(TestIpProtocol::Icmp),
+            TestIpProtocolV2::from(0x01)
+        );
+        assert_eq!(
+            TestIpProtocolV2::from(TestLongProtocol::Udp),
+            TestIpProtocolV2::Udp
+        );
+        assert_eq!(
+            TestIpProtocolV2::from(TestLongProtocol::from(0x2f)),
+            TestIpProtocolV2::from(0x2f)
+        );
+    }
+
+    #[test]
+    fn test_variant_at() {
+        const DECLARED: [Option<TestEtherType>; 4] = [
+            TestEtherType::variant_at(0),
+            TestEtherType::variant_at(1),
+            TestEtherType::variant_at(2),
+            TestEtherType::variant_at(3),
+        ];
+        assert_eq!(
+            DECLARED,
+            [
+                Some(TestEtherType::Ipv4),
+                Some(TestEtherType::Arp),
+                Some(TestEtherType::Ipv6),
+                None
+            ]
+        );
+        for i in 0..TestPriority::COUNT {
+            assert_eq!(TestPriority::variant_at(i).and_then(|v| v.index()), Some(i));
+        }
+        assert_eq!(TestPriority::variant_at(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_sort_matches_values() {
+        // a fixed-seed linear congruential generator, for a repeatable shuffle.
+        let mut state = 0x2545_f491_u32;
+        let mut next = move || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state >> 16
+        };
+
+        let mut protocols: Vec<TestIpProtocol> = Vec::new();
+        let mut ethertypes: Vec<TestEtherType> = Vec::new();
+        for _ in 0..512 {
+            let value = (next() % 8) as u8;
+            let ethertype = [0x0000, 0x0800, 0x0806, 0x0807, 0x86dd, 0xffff][next() as usize % 6];
+            // mix values decoded normally with unknowns holding the same values.
+            if next() % 2 == 0 {
+                protocols.push(value.into());
+                ethertypes.push(ethertype.into());
+            } else {
+                protocols.push(TestIpProtocol::Unknown {
+                    value,
+                    _private: _TestIpProtocol_private::Sealed,
+                });
+                ethertypes.push(TestEtherType::Unknown {
+                    value: ethertype,
+                    _private: _TestEtherType_private::Sealed,
+                });
+            }
+        }
+
+        let mut protocol_values: Vec<u8> = protocols.iter().map(u8::from).collect();
+        protocols.sort();
+        protocol_values.sort_unstable();
+        assert_eq!(
+            protocols.iter().map(u8::from).collect::<Vec<_>>(),
+            protocol_values
+        );
+        assert!(protocols.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let mut ethertype_values: Vec<u16> = ethertypes.iter().map(u16::from).collect();
+        ethertypes.sort();
+        ethertype_values.sort_unstable();
+        assert_eq!(
+            ethertypes.iter().map(u16::from).collect::<Vec<_>>(),
+            ethertype_values
+        );
+        assert!(ethertypes.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_pack_into() {
+        assert_eq!(TestIpProtocol::REPR_MASK, 0xff);
+        assert_eq!(TestEtherType::REPR_MASK, 0xffff);
+        assert_eq!(TestOpcode::REPR_MASK, -1);
+
+        assert_eq!(TestIpProtocol::Tcp.pack_into(0, 8), 0x0000_0600);
+        assert_eq!(TestIpProtocol::Tcp.pack_into(0xffff_ffff, 8), 0xffff_06ff);
+        assert_eq!(TestEtherType::Ipv6.pack_into(0x1234_5678, 16), 0x86dd_5678);
+        assert_eq!(TestEtherType::Ipv6.pack_into(0x1234_5678, 24), 0xdd34_5678);
+        assert_eq!(TestOpcode::Nop.pack_into(0, 4), 0x000f_fff0);
+        assert_eq!(TestWideCode::Large.pack_into(0x1234_5678, 0), 0x8000_0000);
+    }
+
+    #[test]
+    #[should_panic(expected = "`shift` must be less than 32")]
+    fn test_pack_into_shift_too_far() {
+        let _ = TestIpProtocol::Tcp.pack_into(0, 32);
+    }
+
+    #[test]
+    fn test_add_offset() {
+        assert_eq!(TestOpcode::Nop + 1, TestOpcode::Load);
+        assert_eq!(TestOpcode::Load + 2, TestOpcode::Jump);
+        assert_eq!(TestOpcode::Jump + 1, TestOpcode::from(3));
+        assert_eq!(TestOpcode::Jump + -3, TestOpcode::Nop);
+        assert_eq!(TestIpProtocol::from(0xff) + 2, TestIpProtocol::Icmp);
+        assert_eq!(TestEtherType::Ipv4 + 6, TestEtherType::Arp);
+    }
+
+    #[cfg(feature = "bench")]
+    #[test]
+    fn test_sum_decoded() {
+        assert_eq!(TestEtherType::sum_decoded(&[]), 0);
+        assert_eq!(
+            TestEtherType::sum_decoded(&[0x0800, 0x0806, 0x0001]),
+            0x0800 + 0x0806 + 0x0001
+        );
+        assert_eq!(TestOpcode::sum_decoded(&[1, 2]), 3);
+        assert_eq!(TestOpcode::sum_decoded(&[-1, 2]), 1);
+    }
+
+    #[test]
+    fn test_repr_try_into() {
+        assert_eq!(TestEtherType::Ipv6.repr_try_into::<u64>(), Ok(0x86dd));
+        assert_eq!(TestEtherType::Ipv6.repr_try_into::<i32>(), Ok(0x86dd));
+        assert!(TestEtherType::Ipv6.repr_try_into::<i16>().is_err());
+        assert!(TestEtherType::Arp.repr_try_into::<u8>().is_err());
+        assert_eq!(TestOpcode::Nop.repr_try_into::<i64>(), Ok(-1));
+        assert!(TestOpcode::Nop.repr_try_into::<u32>().is_err());
+        assert_eq!(TestIpProtocol::from(0x2f).repr_try_into::<u8>(), Ok(0x2f));
+    }
+
+    #[test]
+    fn test_as_repr_or_sentinel() {
+        assert_eq!(TestIpProtocol::Tcp.as_repr_or_sentinel(0xff), 0x06);
+        assert_eq!(TestIpProtocol::from(0x2f).as_repr_or_sentinel(0xff), 0xff);
+        assert_eq!(TestIpProtocol::from(0x06).as_repr_or_sentinel(0xff), 0x06);
+        assert_eq!(TestOpcode::from(-7).as_repr_or_sentinel(i16::MIN), i16::MIN);
+    }
+
+    #[test]
+    fn test_ascii_try_into_char() {
+        assert_eq!(char::try_from(TestControl::LineFeed), Ok('\n'));
+        assert_eq!(char::try_from(TestControl::Nul), Ok('\0'));
+        assert_eq!(char::try_from(TestControl::from(0x41)), Ok('A'));
+        assert_eq!(
+            char::try_from(TestControl::from(0xe9)),
+            Err(TestControlToCharError { value: 0xe9 })
+        );
+        for i in 0..=0x7f_u8 {
+            let control = TestControl::from(i);
+            assert_eq!(
+                char::try_from(control)
+                    .ok()
+                    .and_then(|c| TestControl::try_from(c).ok()),
+                Some(control)
+            );
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(TestClassifiedOption::classify(0x90), Some("reserved"));
+        assert_eq!(TestClassifiedOption::classify(0xff), Some("experimental"));
+        assert_eq!(TestClassifiedOption::classify(0xfe), Some("reserved"));
+        assert_eq!(TestClassifiedOption::classify(0x02), None);
+        assert_eq!(
+            format!("{:?}", TestClassifiedOption::from(0x90)),
+            "Unknown(144, reserved)"
+        );
+        assert_eq!(
+            format!("{:?}", TestClassifiedOption::from(0xff)),
+            "Unknown(255, experimental)"
+        );
+        assert_eq!(
+            format!("{:?}", TestClassifiedOption::from(0x02)),
+            "Unknown(2)"
+        );
+        assert_eq!(
+            format!("{:?}", TestClassifiedOption::Experimental),
+            "Experimental"
+        );
+        assert_eq!(format!("{:?}", TestIpProtocol::from(0x90)), "Unknown(144)");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(TestIpProtocol::Tcp.to_string(), "Tcp");
+        assert_eq!(TestIpProtocol::from(0x3a).to_string(), "unknown (0x3a)");
+        assert_eq!(TestEtherType::Ipv6.to_string(), "Ipv6");
+        assert_eq!(TestEtherType::from(0x88cc).to_string(), "unknown (0x88cc)");
+        assert_eq!(TestOpcode::from(-2).to_string(), "unknown (0xfffe)");
+    }
+
+    #[test]
+    fn test_name() {
+        const NAMES: [Option<&str>; 2] = [TestEtherType::Arp.name(), TestEtherType::Ipv6.name()];
+        assert_eq!(NAMES, [Some("Arp"), Some("Ipv6")]);
+        assert_eq!(TestIpProtocol::from(0x06).name(), Some("Tcp"));
+        assert_eq!(TestIpProtocol::from(0x3a).name(), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("Tcp".parse(), Ok(TestIpProtocol::Tcp));
+        assert_eq!("Ipv6".parse(), Ok(TestEtherType::Ipv6));
+        assert_eq!(
+            "tcp".parse::<TestIpProtocol>(),
+            Err(TestIpProtocolParseError)
+        );
+        assert_eq!("6".parse::<TestIpProtocol>(), Err(TestIpProtocolParseError));
+        assert_eq!(
+            "Unknown".parse::<TestIpProtocol>(),
+            Err(TestIpProtocolParseError)
+        );
+        assert_eq!("".parse::<TestIpProtocol>(), Err(TestIpProtocolParseError));
+        for (_, variant) in TestEtherType::SORTED_PAIRS {
+            assert_eq!(variant.to_string().parse(), Ok(variant));
+        }
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(
+            TestEtherType::VARIANTS,
+            [TestEtherType::Ipv4, TestEtherType::Arp, TestEtherType::Ipv6]
+        );
+        assert_eq!(
+            TestPriority::VARIANTS,
+            [TestPriority::High, TestPriority::Low, TestPriority::Medium]
+        );
+        assert_eq!(TestSingle::VARIANTS, [TestSingle::Only]);
+        for (i, variant) in TestLongProtocol::VARIANTS.iter().enumerate() {
+            assert_eq!(variant.index(), Some(i));
+        }
+    }
+
+    #[test]
+    fn test_is_known() {
+        const KNOWN: [bool; 2] = [
+            TestIpProtocol::Tcp.is_known(),
+            TestIpProtocol::from_repr(0x3a).is_known(),
+        ];
+        assert_eq!(KNOWN, [true, false]);
+        assert!(!TestIpProtocol::Tcp.is_unknown());
+        assert!(TestIpProtocol::from(0x3a).is_unknown());
+        assert!(!TestIpProtocol::from(0x3a).is_known());
+        for i in 0..=u8::MAX {
+            let protocol = TestIpProtocol::from(i);
+            assert_eq!(protocol.is_known(), protocol.index().is_some());
+        }
+    }
+
+    #[test]
+    fn test_try_from_known() {
+        assert_eq!(
+            TestIpProtocol::try_from_known(0x06),
+            Ok(TestIpProtocol::Tcp)
+        );
+        assert_eq!(
+            TestIpProtocol::try_from_known(0x3a),
+            Err(TestIpProtocolTryFromError { value: 0x3a })
+        );
+        assert_eq!(
+            TestEtherType::try_from_known(0x86dd),
+            Ok(TestEtherType::Ipv6)
+        );
+        for i in 0..=u8::MAX {
+            let strict = TestIpProtocol::try_from_known(i);
+            assert!(strict.map_or(true, |protocol| protocol.is_known()));
+            assert_eq!(strict.is_ok(), TestIpProtocol::from(i).is_known());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> Result<(), serde_json::Error> {
+        for i in 0..=u8::MAX {
+            let a = TestIpProtocol::from(i);
+            let json = serde_json::to_string(&a)?;
+            assert_eq!(json, i.to_string());
+            let b: TestIpProtocol = serde_json::from_str(&json)?;
+            assert_eq!(a, b);
+            assert_eq!(b.is_known(), a.is_known());
+        }
+        assert_eq!(
+            serde_json::from_str::<TestEtherType>("34525")?,
+            TestEtherType::Ipv6
+        );
+        assert_eq!(
+            serde_json::from_str::<TestEtherType>("65535")?,
+            TestEtherType::from(u16::MAX)
+        );
+        assert_eq!(
+            serde_json::from_str::<TestOpcode>("-32768")?,
+            TestOpcode::from(i16::MIN)
+        );
+        assert!(serde_json::from_str::<TestIpProtocol>("256").is_err());
+        assert!(serde_json::from_str::<TestIpProtocol>("\"Tcp\"").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_serialize() -> Result<(), serde_json::Error> {
+        assert_eq!(serde_json::to_string(&TestIpProtocol::Tcp)?, "6");
+        assert_eq!(serde_json::to_string(&TestIpProtocol::from(58))?, "58");
+        assert_eq!(serde_json::to_string(&TestEtherType::Ipv6)?, "34525");
+        assert_eq!(serde_json::to_string(&TestOpcode::Nop)?, "-1");
+        assert_eq!(
+            serde_json::to_string(&[TestIpProtocol::Icmp, TestIpProtocol::from(u8::MAX)])?,
+            "[1,255]"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_as_str() -> Result<(), serde_json::Error> {
+        assert_eq!(serde_json::to_string(&TestNamedProtocol::Tcp)?, r#""Tcp""#);
+        assert_eq!(serde_json::to_string(&TestNamedProtocol::from(58))?, "58");
+        assert_eq!(
+            serde_json::from_str::<TestNamedProtocol>(r#""Icmp""#)?,
+            TestNamedProtocol::Icmp
+        );
+        assert_eq!(
+            serde_json::from_str::<TestNamedProtocol>("6")?,
+            TestNamedProtocol::Tcp
+        );
+        assert_eq!(
+            serde_json::from_str::<TestNamedProtocol>("58")?,
+            TestNamedProtocol::from(58)
+        );
+        assert!(serde_json::from_str::<TestNamedProtocol>(r#""tcp""#).is_err());
+        assert!(serde_json::from_str::<TestNamedProtocol>("256").is_err());
+        assert!(serde_json::from_str::<TestNamedProtocol>("-1").is_err());
+        for i in 0..=u8::MAX {
+            let protocol = TestNamedProtocol::from(i);
+            let json = serde_json::to_string(&protocol)?;
+            assert_eq!(serde_json::from_str::<TestNamedProtocol>(&json)?, protocol);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_unknown_tag() -> Result<(), serde_json::Error> {
+        assert_eq!(serde_json::to_string(&TestTaggedProtocol::Tcp)?, r#""Tcp""#);
+        assert_eq!(
+            serde_json::to_string(&TestTaggedProtocol::from(58))?,
+            r#"{"unassigned":58}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<TestTaggedProtocol>(r#"{"unassigned":58}"#)?,
+            TestTaggedProtocol::from(58)
+        );
+        assert_eq!(
+            serde_json::from_str::<TestTaggedProtocol>("58")?,
+            TestTaggedProtocol::from(58)
+        );
+        assert!(serde_json::from_str::<TestTaggedProtocol>(r#"{"Unknown":58}"#).is_err());
+        assert!(serde_json::from_str::<TestTaggedProtocol>(r#"{"unassigned":256}"#).is_err());
+        assert!(serde_json::from_str::<TestTaggedProtocol>(r#"{"unassigned":58,"x":1}"#).is_err());
+        // maps are only accepted with a tag to look for.
+        assert!(serde_json::from_str::<TestNamedProtocol>(r#"{"Unknown":58}"#).is_err());
+        for i in 0..=u8::MAX {
+            let protocol = TestTaggedProtocol::from(i);
+            let json = serde_json::to_string(&protocol)?;
+            assert_eq!(serde_json::from_str::<TestTaggedProtocol>(&json)?, protocol);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format() {
+        // logging needs a global logger, which tests don't have; check the impls exist.
+        fn assert_format<T: defmt::Format>() {}
+        assert_format::<TestIpProtocol>();
+        assert_format::<TestEtherType>();
+        assert_format::<TestOpcode>();
+        assert_format::<TestWideCode>();
+    }
+
+    #[cfg(feature = "clap")]
+    #[test]
+    fn test_clap_value_enum() {
+        use clap::ValueEnum;
+
+        let names: Vec<String> = TestEtherType::value_variants()
+            .iter()
+            .filter_map(ValueEnum::to_possible_value)
+            .map(|v| v.get_name().to_owned())
+            .collect();
+        assert_eq!(names, ["Ipv4", "Arp", "Ipv6"]);
+        assert_eq!(
+            TestEtherType::from_str("Arp", false),
+            Ok(TestEtherType::Arp)
+        );
+        assert_eq!(TestEtherType::from_str("arp", true), Ok(TestEtherType::Arp));
+        assert!(TestEtherType::from_str("Unknown", false).is_err());
+        assert!(TestEtherType::from(0x88cc).to_possible_value().is_none());
+    }
+
+    #[test]
+    fn test_int_aliases() {
+        const TABLE: [TestEtherType; 2] = [
+            TestEtherType::from_int(0x0806),
+            TestEtherType::from_int(0x88cc),
+        ];
+        const VALUES: [u16; 2] = [TABLE[0].to_int(), TABLE[1].to_int()];
+        assert_eq!(TABLE, [TestEtherType::Arp, TestEtherType::from(0x88cc)]);
+        assert_eq!(VALUES, [0x0806, 0x88cc]);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() -> arbitrary::Result<()> {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        assert_eq!(TestIpProtocol::size_hint(0), (1, Some(1)));
+        assert_eq!(TestEtherType::size_hint(0), (2, Some(2)));
+
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        let mut u = Unstructured::new(&bytes);
+        let mut protocols = Vec::new();
+        while !u.is_empty() {
+            protocols.push(TestIpProtocol::arbitrary(&mut u)?);
+        }
+        assert_eq!(protocols.len(), 256);
+        assert!(protocols.contains(&TestIpProtocol::Tcp));
+        assert!(protocols.iter().any(TestIpProtocol::is_unknown));
+        Ok(())
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn test_proptest_strategies() {
+        use proptest::arbitrary::any;
+        use proptest::strategy::{Strategy, ValueTree};
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let mut unknown = 0;
+        for _ in 0..256 {
+            let any = any::<TestEtherType>()
+                .new_tree(&mut runner)
+                .map(|tree| tree.current());
+            if any.map_or(false, |v| v.is_unknown()) {
+                unknown += 1;
+            }
+            let known = TestEtherType::known_strategy()
+                .new_tree(&mut runner)
+                .map(|tree| tree.current());
+            assert!(
+                known.as_ref().map_or(false, TestEtherType::is_known),
+                "{known:?}"
+            );
+        }
+        assert!(unknown > 0);
+
+        let single = TestSingle::known_strategy()
+            .new_tree(&mut runner)
+            .map(|tree| tree.current());
+        assert_eq!(single.ok(), Some(TestSingle::Only));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_known() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x5eed);
+        let mut seen = [false; TestEtherType::COUNT];
+        for _ in 0..256 {
+            let ethertype = TestEtherType::random_known(&mut rng);
+            let Some(seen) = ethertype.index().and_then(|i| seen.get_mut(i)) else {
+                panic!("{ethertype:?} isn't a named variant");
+            };
+            *seen = true;
+        }
+        assert_eq!(seen, [true; TestEtherType::COUNT]);
+        assert_eq!(TestSingle::random_known(&mut rng), TestSingle::Only);
+    }
+
+    #[test]
+    fn test_eq_repr() {
+        assert!(TestIpProtocol::Tcp == 6);
+        assert!(6 == TestIpProtocol::Tcp);
+        assert!(TestIpProtocol::Tcp != 7);
+        assert!(TestIpProtocol::from(0x3a) == 0x3a_u8);
+        assert!(0x86dd_u16 == TestEtherType::Ipv6);
+        assert!(TestOpcode::Nop == -1);
+        let unknown_tcp = TestStrictProtocol::Unknown {
+            value: 0x06,
+            _private: _TestStrictProtocol_private::Sealed,
+        };
+        assert!(unknown_tcp == 6 && TestStrictProtocol::Tcp == 6);
+    }
+
+    #[test]
+    fn test_ord_repr() {
+        assert!(TestIpProtocol::Tcp < 0x30);
+        assert!(0x30 > TestIpProtocol::Tcp);
+        assert!(TestIpProtocol::Tcp >= 6);
+        assert!(TestIpProtocol::from(0x3a) > 0x30_u8);
+        assert!(TestOpcode::Nop < 0);
+        for i in 0..=u8::MAX {
+            for j in [0x01, 0x06, 0x80] {
+                let protocol = TestIpProtocol::from(i);
+                assert_eq!(
+                    protocol.partial_cmp(&j),
+                    Some(protocol.cmp(&TestIpProtocol::from(j)))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_iter() {
+        assert!(TestEtherType::iter().eq(TestEtherType::VARIANTS));
+        assert_eq!(TestEtherType::iter().len(), TestEtherType::COUNT);
+        assert_eq!(TestEtherType::iter().next_back(), Some(TestEtherType::Ipv6));
+        assert!(TestEtherType::iter().all(|v| v.is_known()));
+        assert!(TestSingle::iter().eq([TestSingle::Only]));
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(TestSingle::COUNT, 1);
+        assert_eq!(TestNoTrailingComma::COUNT, 2);
+        assert_eq!(TestIpProtocol::COUNT, 2);
+        assert_eq!(TestEtherType::COUNT, 3);
+        assert_eq!(TestOpcode::COUNT, 4);
+        assert_eq!(TestLongProtocol::COUNT, 4);
+        let mut per_variant = [0_u32; TestEtherType::COUNT];
+        for i in [0x0800, 0x0806, 0x0806, 0x88cc] {
+            if let Some(count) = TestEtherType::from(i)
+                .index()
+                .and_then(|i| per_variant.get_mut(i))
+            {
+                *count += 1;
+            }
+        }
+        assert_eq!(per_variant, [1, 2, 0]);
+    }
+
+    #[test]
+    fn test_next_prev() {
+        assert_eq!(TestEtherType::Ipv4.next(), Some(TestEtherType::Arp));
+        assert_eq!(TestEtherType::Arp.next(), Some(TestEtherType::Ipv6));
+        assert_eq!(TestEtherType::Ipv6.next(), None);
+        assert_eq!(TestEtherType::Ipv6.prev(), Some(TestEtherType::Arp));
+        assert_eq!(TestEtherType::Ipv4.prev(), None);
+        assert_eq!(TestEtherType::from(0x0801).next(), Some(TestEtherType::Arp));
+        assert_eq!(
+            TestEtherType::from(0x0801).prev(),
+            Some(TestEtherType::Ipv4)
+        );
+        assert_eq!(TestEtherType::from(0xffff).next(), None);
+        assert_eq!(TestEtherType::from(0x0000).prev(), None);
+        // declaration order doesn't matter.
+        assert_eq!(TestPriority::Low.next(), Some(TestPriority::Medium));
+        assert_eq!(TestPriority::High.prev(), Some(TestPriority::Medium));
+        assert_eq!(TestSingle::Only.next(), None);
+    }
+
+    #[test]
+    fn test_min_max_known() {
+        const BOUNDS: [TestPriority; 2] = [TestPriority::min_known(), TestPriority::max_known()];
+        assert_eq!(BOUNDS, [TestPriority::Low, TestPriority::High]);
+        assert_eq!(TestEtherType::min_known(), TestEtherType::Ipv4);
+        assert_eq!(TestEtherType::max_known(), TestEtherType::Ipv6);
+        assert_eq!(TestOpcode::min_known(), TestOpcode::Nop);
+        assert_eq!(TestSingle::min_known(), TestSingle::Only);
+        assert_eq!(TestSingle::max_known(), TestSingle::Only);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(format!("{:x}", TestIpProtocol::Tcp), "6");
+        assert_eq!(format!("{:#x}", TestIpProtocol::Tcp), "0x6");
+        assert_eq!(format!("{:#04x}", TestIpProtocol::Tcp), "0x06");
+        assert_eq!(format!("{:X}", TestEtherType::Ipv6), "86DD");
+        assert_eq!(format!("{:#X}", TestEtherType::from(0x88cc)), "0x88CC");
+        assert_eq!(format!("{:06x}", TestEtherType::Arp), "000806");
+    }
+
+    #[test]
+    fn test_binary() {
+        assert_eq!(format!("{:b}", TestIpProtocol::Tcp), "110");
+        assert_eq!(format!("{:#b}", TestIpProtocol::Tcp), "0b110");
+        assert_eq!(format!("{:#010b}", TestTcpFlag::Ack), "0b00010000");
+        assert_eq!(format!("{:08b}", TestTcpFlag::from(0x12)), "00010010");
+    }
+
+    #[test]
+    fn test_visibility() {
+        assert_eq!(TestPrivateProtocol::from(0x06), TestPrivateProtocol::Tcp);
+        assert_eq!(
+            TestCrateProtocol::try_from_known(0x3a),
+            Err(TestCrateProtocolTryFromError { value: 0x3a })
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Header {
+            protocol: TestDefaultProtocol,
+        }
+        assert_eq!(TestDefaultProtocol::default(), TestDefaultProtocol::Tcp);
+        assert_eq!(Header::default().protocol, TestDefaultProtocol::Tcp);
+    }
+
+    #[test]
+    fn test_unknown_renamed() {
+        let other = TestOtherProtocol::from_repr(0x11);
+        assert!(matches!(
+            other,
+            TestOtherProtocol::Other { value: 0x11, .. }
+        ));
+        assert!(other.is_unknown());
+        assert_eq!(other.to_repr(), 0x11);
+        assert_eq!(other.name(), None);
+        assert_eq!(format!("{other:?}"), "Other(17)");
+        assert_eq!(TestOtherProtocol::from_repr(0x06), TestOtherProtocol::Tcp);
+    }
+
+    #[test]
+    fn test_alias() {
+        assert_eq!(TestAliasedProtocol::IpIp.to_repr(), 0x04);
+        assert_eq!(TestAliasedProtocol::IpIp, TestAliasedProtocol::Ipv4);
+        assert!(matches!(
+            TestAliasedProtocol::from_repr(0x04),
+            TestAliasedProtocol::Ipv4
+        ));
+        assert_eq!(TestAliasedProtocol::IpIp.name(), Some("IpIp"));
+        assert_eq!(TestAliasedProtocol::COUNT, 4);
+        assert_eq!(TestAliasedProtocol::coverage(), 3);
+        assert!(matches!(
+            TestAliasedProtocol::Tcp.prev(),
+            Some(TestAliasedProtocol::Ipv4)
+        ));
+        assert!(matches!(
+            TestAliasedProtocol::Icmp.next(),
+            Some(TestAliasedProtocol::Ipv4)
+        ));
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(TestIpProtocol::Tcp.to_be_bytes(), [0x06]);
+        assert_eq!(
+            TestWideCode::from_repr(0x0102_0304).to_be_bytes(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            TestWideCode::from_repr(0x0102_0304).to_le_bytes(),
+            [4, 3, 2, 1]
+        );
+        assert_eq!(
+            TestWideCode::from_be_bytes([1, 2, 3, 4]).to_repr(),
+            0x0102_0304
+        );
+        assert_eq!(
+            TestWideCode::from_le_bytes([4, 3, 2, 1]).to_repr(),
+            0x0102_0304
+        );
+        assert_eq!(
+            TestOpcode::from_be_bytes((-1i16).to_be_bytes()),
+            TestOpcode::Nop
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_variant() {
+        assert_eq!(TestDeprecatedFlag::Ece.to_repr(), 0x40);
+        assert_eq!(TestDeprecatedFlag::from_repr(0x40), TestDeprecatedFlag::Ece);
+        assert_eq!(format!("{:?}", TestDeprecatedFlag::Ece), "Ece");
+    }
 
-                        assert_eq!(
-                            a_cmp_b,
-                            Ordering::Less,
-                            "[cmp] a {a:?} ({a_int}) !< b {b:?} ({b_int})"
-                        );
-                        assert_eq!(
-                            a_pcmp_b,
-                            Some(Ordering::Less),
-                            "[pcmp] a {a:?} ({a_int}) !< b {b:?} ({b_int})"
-                        );
-                        assert_eq!(
-                            b_cmp_a,
-                            Ordering::Greater,
-                            "[cmp] b {b:?} ({b_int}) !> a {a:?} ({a_int})"
-                        );
-                        assert_eq!(
-                            b_pcmp_a,
-                            Some(Ordering::Greater),
-                            "[pcmp] b {b:?} ({b_int}) !> a {a:?} ({a_int})"
-                        );
+    #[test]
+    fn test_discriminant() {
+        const TCP: u8 = TestIpProtocol::Tcp.discriminant();
+        assert_eq!(TCP, 0x06);
+        assert_eq!(TestIpProtocol::from_repr(0x3a).discriminant(), 0x3a);
+        assert_eq!(TestOpcode::Nop.discriminant(), -1);
+    }
 
-                        // extra checks specifically for invariants that should hold
-                        // for correct implementations of `Ord` and `PartialOrd`.
-                        // (just in case the above is ever modified carelessly).
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(TestIpProtocol::try_from("Tcp"), Ok(TestIpProtocol::Tcp));
+        assert_eq!(
+            TestIpProtocol::try_from("tcp"),
+            Err(TestIpProtocolParseError)
+        );
+        assert_eq!(
+            TestIpProtocol::try_from("Udp"),
+            "Udp".parse::<TestIpProtocol>()
+        );
+    }
 
-                        // check that `cmp` and `partial_cmp` agree.
-                        assert_eq!(a_pcmp_b, Some(a_cmp_b), "a cmp b != a pcmp b");
-                        assert_eq!(b_pcmp_a, Some(b_cmp_a), "b cmp a != b pcmp a!");
+    #[test]
+    fn test_as_static_str() {
+        assert_eq!(TestIpProtocol::Tcp.as_static_str(), Some("Tcp"));
+        assert_eq!(TestIpProtocol::from_repr(0x3a).as_static_str(), None);
+    }
 
-                        // check reversing args also reverses the `cmp` result.
-                        assert_eq!(a_cmp_b, b_cmp_a.reverse());
-                        assert_eq!(b_cmp_a, a_cmp_b.reverse());
-                    }
+    #[test]
+    fn test_checked_from() {
+        const TCP: Option<TestIpProtocol> = TestIpProtocol::checked_from(0x06);
+        assert_eq!(TCP, Some(TestIpProtocol::Tcp));
+        assert_eq!(TestIpProtocol::checked_from(0x99), None);
+        assert!(TestIpProtocol::from(0x99).is_unknown());
+        assert!(matches!(
+            TestAliasedProtocol::checked_from(0x04),
+            Some(TestAliasedProtocol::Ipv4)
+        ));
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_read_from_prefix() {
+        let bytes = 0x0102_0304_u32.to_ne_bytes();
+        assert_eq!(
+            TestWideCode::read_from_prefix(&bytes).map(TestWideCode::to_repr),
+            Some(0x0102_0304)
+        );
+        assert_eq!(
+            TestIpProtocol::read_from_prefix(&[0x06, 0xff]),
+            Some(TestIpProtocol::Tcp)
+        );
+        assert_eq!(TestWideCode::read_from_prefix(&bytes[..3]), None);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        assert_eq!(TestIpProtocol::from_bits(0x06), TestIpProtocol::Tcp);
+        assert_eq!(TestIpProtocol::Icmp.to_bits(), 0x01);
+        let decoded =
+            TestIpProtocol::iter_from_bytes(&[0x06, 0x01, 0x3a]).map(Iterator::collect::<Vec<_>>);
+        assert_eq!(
+            decoded,
+            Some(vec![
+                TestIpProtocol::Tcp,
+                TestIpProtocol::Icmp,
+                TestIpProtocol::from_repr(0x3a)
+            ])
+        );
+        let words = [0x0000_0001_u32, 0x8000_0000];
+        let bytes: &[u8] = bytemuck::cast_slice(&words);
+        assert_eq!(
+            TestWideCode::iter_from_bytes(bytes).map(Iterator::count),
+            Some(2)
+        );
+        assert!(TestWideCode::iter_from_bytes(&bytes[..7]).is_none());
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(TestIpProtocol)).ok();
+        let any_of = schema.as_ref().map(|schema| &schema["anyOf"]);
+        assert_eq!(
+            any_of.map(|any_of| &any_of[0]),
+            Some(&serde_json::json!({ "title": "Icmp", "const": 1 }))
+        );
+        assert_eq!(
+            any_of.map(|any_of| &any_of[2]["type"]),
+            Some(&serde_json::json!("integer"))
+        );
+
+        let schema = serde_json::to_value(schemars::schema_for!(TestNamedProtocol)).ok();
+        assert_eq!(
+            schema.as_ref().map(|schema| &schema["anyOf"][0]["const"]),
+            Some(&serde_json::json!("Icmp"))
+        );
+
+        let schema = serde_json::to_value(schemars::schema_for!(TestTaggedProtocol)).ok();
+        let unknown = schema.as_ref().map(|schema| &schema["anyOf"][2]);
+        assert_eq!(
+            unknown.map(|unknown| &unknown["required"]),
+            Some(&serde_json::json!(["unassigned"]))
+        );
+        assert_eq!(
+            unknown.map(|unknown| &unknown["properties"]["unassigned"]["type"]),
+            Some(&serde_json::json!("integer"))
+        );
+    }
+
+    #[test]
+    fn test_invoke_in_fn() {
+        yikes_intenum! {
+            #[yikes(sorted)]
+            pub enum Local(u8) {
+                Icmp = 0x01,
+                Tcp = 0x06,
+            }
+        }
+        // would share a sealing module with `Local`, if it were named in snake case.
+        #[allow(non_camel_case_types)]
+        mod snake {
+            yikes_intenum! {
+                pub enum Test_Http_Code(u16) {
+                    Ok = 200,
+                }
+            }
+            yikes_intenum! {
+                pub enum TestHttpCode(u16) {
+                    Ok = 200,
                 }
             }
         }
+        assert_eq!(Local::from(0x06), Local::Tcp);
+        assert_eq!(snake::TestHttpCode::from(200), snake::TestHttpCode::Ok);
+        assert_eq!(snake::Test_Http_Code::from(200), snake::Test_Http_Code::Ok);
     }
 
     #[test]
-    fn test_ipprotocol_hash() {
-        for i in 0..u8::MAX {
-            let a: TestIpProtocol = i.into();
-            let b = TestIpProtocol::Unknown {
-                value: i,
-                _private: _test_ip_protocol_private::Sealed,
+    fn test_no_ord_no_hash() {
+        // our own impls, if any, would conflict with these.
+        impl PartialOrd for TestMinimalProtocol {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                other.to_repr().partial_cmp(&self.to_repr())
+            }
+        }
+        impl Hash for TestMinimalProtocol {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.name().hash(state);
+            }
+        }
+        assert!(TestMinimalProtocol::Tcp < TestMinimalProtocol::Icmp);
+        assert_eq!(TestMinimalProtocol::from(0x06), TestMinimalProtocol::Tcp);
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(TestSignedCode::from(i8::MIN), TestSignedCode::Min);
+        assert_eq!(TestSignedCode::from(-1), TestSignedCode::Negative);
+        assert_eq!(TestSignedCode::from(i8::MAX), TestSignedCode::Max);
+        assert_eq!(i8::from(TestSignedCode::Min), -128);
+        assert_eq!(TestSignedCode::from(-2).to_repr(), -2);
+        assert!(TestSignedCode::from(-2).is_unknown());
+        assert!(TestSignedCode::from(i8::MIN + 1).is_unknown());
+        assert!(TestSignedCode::from(i8::MAX - 1).is_unknown());
+
+        // by signed value, not by bit pattern.
+        let mut codes = [
+            TestSignedCode::Max,
+            TestSignedCode::Zero,
+            TestSignedCode::from(-2),
+            TestSignedCode::Negative,
+            TestSignedCode::Min,
+        ];
+        codes.sort();
+        assert_eq!(codes.map(TestSignedCode::to_repr), [-128, -2, -1, 0, 127]);
+        assert!(TestSignedCode::Min < 0);
+        assert!(TestSignedCode::min_known() == TestSignedCode::Min);
+        assert!(TestSignedCode::max_known() == TestSignedCode::Max);
+        assert_eq!(TestSignedCode::Zero.prev(), Some(TestSignedCode::Negative));
+        assert_eq!(TestSignedCode::Negative.prev(), Some(TestSignedCode::Min));
+        assert_eq!(TestSignedCode::known_count_in_range(-128, -1), 2);
+
+        assert_eq!(format!("{:?}", TestSignedCode::Min), "Min");
+        assert_eq!(format!("{:?}", TestSignedCode::from(-2)), "Unknown(-2)");
+        assert_eq!(
+            format!("{:?}", TestSignedCode::from(i8::MIN + 1)),
+            "Unknown(-127)"
+        );
+    }
+
+    #[test]
+    fn test_widening() {
+        assert_eq!(u16::from(TestIpProtocol::Tcp), 0x06);
+        assert_eq!(u32::from(TestIpProtocol::Tcp), 0x06);
+        assert_eq!(u64::from(TestIpProtocol::from(0xff)), 0xff);
+        assert_eq!(u128::from(TestIpProtocol::Tcp), 0x06);
+        assert_eq!(i16::from(TestIpProtocol::Tcp), 0x06);
+        assert_eq!(u64::from(TestWideCode::Max), 0xffff_ffff);
+        assert_eq!(i32::from(TestOpcode::Nop), -1);
+        assert_eq!(i128::from(TestSignedCode::Min), -128);
+    }
+
+    #[cfg(feature = "num_enum")]
+    #[test]
+    fn test_num_enum() {
+        use num_enum::{FromPrimitive, TryFromPrimitive};
+
+        assert_eq!(TestIpProtocol::from_primitive(0x06), TestIpProtocol::Tcp);
+        assert!(TestIpProtocol::from_primitive(0x3a).is_unknown());
+        assert_eq!(
+            TestIpProtocol::try_from_primitive(0x06),
+            Ok(TestIpProtocol::Tcp)
+        );
+        assert_eq!(
+            TestIpProtocol::try_from_primitive(0x3a),
+            Err(TestIpProtocolTryFromError { value: 0x3a })
+        );
+        assert_eq!(<TestIpProtocol as TryFromPrimitive>::NAME, "TestIpProtocol");
+    }
+
+    #[test]
+    fn test_from_name_ignore_case() {
+        assert_eq!(
+            TestIpProtocol::from_name_ignore_case("Tcp"),
+            Some(TestIpProtocol::Tcp)
+        );
+        assert_eq!(
+            TestIpProtocol::from_name_ignore_case("tcp"),
+            Some(TestIpProtocol::Tcp)
+        );
+        assert_eq!(
+            TestIpProtocol::from_name_ignore_case("ICMP"),
+            Some(TestIpProtocol::Icmp)
+        );
+        assert_eq!(TestIpProtocol::from_name_ignore_case("udp"), None);
+        assert_eq!(
+            "tcp".parse::<TestIpProtocol>(),
+            Err(TestIpProtocolParseError)
+        );
+    }
+
+    #[test]
+    fn test_entries() {
+        assert_eq!(
+            TestIpProtocol::entries().collect::<Vec<_>>(),
+            [("Icmp", 0x01), ("Tcp", 0x06)]
+        );
+        assert_eq!(TestOpcode::entries().last(), Some(("Jump", 2)));
+        assert_eq!(TestOpcode::entries().count(), TestOpcode::COUNT);
+    }
+
+    #[test]
+    fn test_repr_override() {
+        // a `repr(C)` enum with fields is laid out as a tagged union: a `u8` tag,
+        // then a union of each variant's fields, which is `u8`-sized here.
+        assert_eq!(core::mem::size_of::<TestCProtocol>(), 2);
+        assert_eq!(TestCProtocol::from(0x06), TestCProtocol::Tcp);
+        assert_eq!(TestCProtocol::from(0x3a).to_repr(), 0x3a);
+    }
+
+    #[test]
+    fn test_from_ref_repr() {
+        let bytes = [0x06_u8, 0x01, 0x3a];
+        assert_eq!(TestIpProtocol::from(&bytes[0]), TestIpProtocol::Tcp);
+        let decoded: Vec<TestIpProtocol> = bytes.iter().map(TestIpProtocol::from).collect();
+        assert_eq!(
+            decoded,
+            [
+                TestIpProtocol::Tcp,
+                TestIpProtocol::Icmp,
+                TestIpProtocol::from(0x3a)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_closest_known() {
+        assert_eq!(TestIpProtocol::Tcp.closest_known(), TestIpProtocol::Tcp);
+        assert_eq!(
+            TestIpProtocol::from(0x00).closest_known(),
+            TestIpProtocol::Icmp
+        );
+        assert_eq!(
+            TestIpProtocol::from(0x03).closest_known(),
+            TestIpProtocol::Icmp
+        );
+        assert_eq!(
+            TestIpProtocol::from(0x04).closest_known(),
+            TestIpProtocol::Tcp
+        );
+        assert_eq!(
+            TestIpProtocol::from(0xff).closest_known(),
+            TestIpProtocol::Tcp
+        );
+        // 0x05 is as close to 0x04 as to 0x06; the lower value wins.
+        assert!(matches!(
+            TestAliasedProtocol::from(0x05).closest_known(),
+            TestAliasedProtocol::Ipv4
+        ));
+        assert_eq!(
+            TestSignedCode::from(-64).closest_known(),
+            TestSignedCode::Negative
+        );
+        assert_eq!(
+            TestSignedCode::from(-65).closest_known(),
+            TestSignedCode::Min
+        );
+        assert_eq!(
+            TestSignedCode::from(63).closest_known(),
+            TestSignedCode::Zero
+        );
+        assert_eq!(
+            TestSignedCode::from(64).closest_known(),
+            TestSignedCode::Max
+        );
+    }
+
+    #[test]
+    fn test_from_repr_lookup_matches_match() {
+        // these decode through a lookup table; `checked_from` always uses a `match`.
+        for value in u8::MIN..=u8::MAX {
+            let decoded = TestIpProtocol::from_repr(value);
+            assert_eq!(decoded.to_repr(), value);
+            assert_eq!(
+                TestIpProtocol::checked_from(value).is_none(),
+                decoded.is_unknown()
+            );
+            // aliases compare equal, so compare by name to tell them apart.
+            assert_eq!(
+                TestAliasedProtocol::from_repr(value).name(),
+                TestAliasedProtocol::checked_from(value).and_then(|known| known.name())
+            );
+        }
+        for value in i8::MIN..=i8::MAX {
+            let decoded = TestSignedCode::from_repr(value);
+            assert_eq!(decoded.to_repr(), value);
+            assert_eq!(
+                TestSignedCode::checked_from(value),
+                (!decoded.is_unknown()).then_some(decoded)
+            );
+        }
+        assert!(matches!(
+            TestAliasedProtocol::from_repr(0x04),
+            TestAliasedProtocol::Ipv4
+        ));
+        // too spread out for a table.
+        assert_eq!(TestWideCode::from_repr(0x8000_0000), TestWideCode::Large);
+        assert!(TestWideCode::from_repr(0x8000_0001).is_unknown());
+    }
+
+    #[test]
+    fn test_ranged_variants() {
+        for value in u8::MIN..=u8::MAX {
+            let protocol = TestRangedProtocol::from(value);
+            let expected = match value {
+                0x01 => Some(TestRangedProtocol::Icmp),
+                0x06 => Some(TestRangedProtocol::Tcp),
+                0xfd..=0xfe => Some(TestRangedProtocol::Experimental),
+                _ => None,
             };
+            assert_eq!(TestRangedProtocol::checked_from(value), expected);
+            assert_eq!(protocol.index(), expected.and_then(|known| known.index()));
+            assert_eq!(
+                protocol.is_unknown(),
+                protocol.to_repr() == value && expected.is_none()
+            );
+        }
+        assert_eq!(TestRangedProtocol::Experimental.to_repr(), 0xfd);
+        assert_eq!(TestRangedProtocol::from(0xfe).to_repr(), 0xfd);
 
-            #[allow(unused_qualifications, clippy::type_complexity)]
-            let hashers: Vec<(
-                &str,
-                Box<dyn core::hash::Hasher>,
-                Box<dyn core::hash::Hasher>,
-            )> = vec![
-                (
-                    "std::collections::hash_map::DefaultHasher",
-                    Box::new(std::collections::hash_map::DefaultHasher::new()) as _,
-                    Box::new(std::collections::hash_map::DefaultHasher::new()) as _,
-                ),
-                (
-                    "FnvHasher",
-                    Box::new(fnv::FnvBuildHasher::default().build_hasher()) as _,
-                    Box::new(fnv::FnvBuildHasher::default().build_hasher()) as _,
-                ),
-            ];
+        // too wide a span for the lookup table, so decoded by the `match`.
+        for value in i16::MIN..=i16::MAX {
+            let expected = match value {
+                -0x200..=-0x100 => Some(TestRangedCode::Reserved),
+                0 => Some(TestRangedCode::Ok),
+                0x1000..=0x10ff => Some(TestRangedCode::Vendor),
+                _ => None,
+            };
+            assert_eq!(TestRangedCode::checked_from(value), expected);
+            assert_eq!(TestRangedCode::from(value).is_known(), expected.is_some());
+        }
+        assert_eq!(TestRangedCode::Reserved.to_repr(), -0x200);
+        assert_eq!(TestRangedCode::Vendor.to_repr(), 0x1000);
+    }
 
-            for (hasher_kind, mut hasher_a, mut hasher_b) in hashers {
-                #[allow(clippy::unreadable_literal)]
-                let noises: &[Option<u64>] = &[
-                    None,
-                    Some(18223650421099562965_u64),
-                    Some(579348513557276885_u64),
-                    Some(6018745257231369041_u64),
-                    Some(4974397919804797078_u64),
-                    Some(6574880736321336287_u64),
-                    Some(8334883869055102477_u64),
-                    Some(8077341428061256032_u64),
-                    Some(8702568753483328048_u64),
-                ];
-                for noise in noises {
-                    let assert_hashers_match =
-                        |hasher_a: &mut dyn Hasher, hasher_b: &mut dyn Hasher| {
-                            let ha = &mut hasher_a.finish();
-                            let hb = &mut hasher_b.finish();
-                            assert_eq!(
-                                ha,
-                                hb,
-                                "[kind={hasher_kind}, noise={noise:?}] hash{{a {a:?} ({})}} {ha} != hash{{b {b:?} ({})}} {hb}",
-                                u8::from(&a),
-                                u8::from(&b),
-                            );
-                        };
+    #[test]
+    fn test_ranged_closest_known() {
+        assert_eq!(
+            TestRangedProtocol::from(0xfe).closest_known().index(),
+            TestRangedProtocol::Experimental.index()
+        );
+        assert_eq!(
+            TestRangedProtocol::from(0xff).closest_known().index(),
+            TestRangedProtocol::Experimental.index()
+        );
+        assert_eq!(
+            TestRangedProtocol::from(0x80).closest_known().index(),
+            TestRangedProtocol::Tcp.index()
+        );
+        assert_eq!(
+            TestRangedCode::from(0x800).closest_known(),
+            TestRangedCode::Ok
+        );
+        assert_eq!(
+            TestRangedCode::from(0x900).closest_known(),
+            TestRangedCode::Vendor
+        );
+        assert_eq!(
+            TestRangedCode::from(i16::MAX).closest_known(),
+            TestRangedCode::Vendor
+        );
+        assert_eq!(
+            TestRangedCode::from(-0xff).closest_known(),
+            TestRangedCode::Reserved
+        );
+    }
 
-                    if let Some(noise) = noise {
-                        noise.hash(&mut hasher_a);
-                        noise.hash(&mut hasher_b);
-                        assert_hashers_match(&mut hasher_a, &mut hasher_b);
-                    }
-                    a.hash(&mut hasher_a);
-                    b.hash(&mut hasher_b);
-                    assert_hashers_match(&mut hasher_a, &mut hasher_b);
-                }
-                assert_eq!(
-                    a,
-                    b,
-                    "[kind={hasher_kind}] hash{{a}} == hash{{b}} must imply a == b, but: a {a:?} ({}) != b {b:?} ({})",
-                    u8::from(&a),
-                    u8::from(&b)
-                );
+    #[test]
+    fn test_ranged_counts() {
+        assert_eq!(TestRangedProtocol::coverage(), 4);
+        assert_eq!(TestRangedProtocol::known_count_in_range(0xfe, 0xfe), 1);
+        assert_eq!(TestRangedProtocol::known_count_in_range(0x02, 0xfd), 2);
+        assert_eq!(TestRangedProtocol::known_count_in_range(0xff, 0xff), 0);
+        assert_eq!(TestRangedCode::coverage(), 0x101 + 1 + 0x100);
+        assert_eq!(TestRangedCode::known_count_in_range(-0x100, 0), 2);
+        assert_eq!(TestDenseRange::coverage(), 256);
+        assert!(TestDenseRange::is_exhaustive());
+        assert!(!TestRangedProtocol::is_exhaustive());
+        assert!(!TestRangedCode::is_exhaustive());
+        assert!(!TestAliasedProtocol::is_exhaustive());
+        assert_eq!(TestDenseRange::known_count_in_range(0x02, 0x05), 3);
+        assert!(TestDenseRange::from(0x80).is_known());
+    }
+
+    #[test]
+    fn test_from_index() {
+        let mut counts = [0_usize; TestIpProtocol::COUNT];
+        for value in [0x01, 0x06, 0x06, 0x99] {
+            if let Some(i) = TestIpProtocol::from(value).index() {
+                counts[i] += 1;
             }
         }
+        assert_eq!(counts, [1, 2]);
+        for (i, variant) in TestIpProtocol::VARIANTS.into_iter().enumerate() {
+            assert_eq!(TestIpProtocol::from_index(i), Some(variant));
+            assert_eq!(variant.index(), Some(i));
+        }
+        assert_eq!(TestIpProtocol::from_index(TestIpProtocol::COUNT), None);
     }
 
     #[test]
-    fn test_ipprotocol_hash_different() {
-        for i in 0..u8::MAX {
-            for a in [
-                i.into(),
-                TestIpProtocol::Unknown {
-                    value: i,
-                    _private: _test_ip_protocol_private::Sealed,
-                },
-            ] {
-                for j in 0..=u8::MAX {
-                    if i == j {
-                        continue;
-                    }
-                    for b in [
-                        j.into(),
-                        TestIpProtocol::Unknown {
-                            value: j,
-                            _private: _test_ip_protocol_private::Sealed,
-                        },
-                    ] {
-                        let mut hasher_a =
-                            Box::new(std::collections::hash_map::DefaultHasher::new());
-                        let mut hasher_b =
-                            Box::new(std::collections::hash_map::DefaultHasher::new());
-                        a.hash(&mut hasher_a);
-                        b.hash(&mut hasher_b);
-                        let ha = hasher_a.finish();
-                        let hb = hasher_b.finish();
-                        // a != b almost surely implies hash{{a}} != hash{{b}}.
-                        assert_ne!(ha, hb, "Different values yielded same hashes: hash{{a {a:?} ({int_a})}} ({ha}) == hash{{b {b:?} ({int_b})}} ({hb})", int_a=u8::from(&a), ha=ha, int_b=u8::from(&b), hb=hb);
-                    }
-                }
+    fn test_match_with() {
+        let describe = |protocol: TestIpProtocol| {
+            protocol.match_with(
+                || "icmp".to_owned(),
+                || "tcp".to_owned(),
+                |value| format!("{value:#x}"),
+            )
+        };
+        assert_eq!(describe(TestIpProtocol::Icmp), "icmp");
+        assert_eq!(describe(TestIpProtocol::Tcp), "tcp");
+        assert_eq!(describe(TestIpProtocol::from(0x99)), "0x99");
+        assert_eq!(
+            TestOtherProtocol::from(0x99).match_with(|| 0, || 1, |_| 2),
+            2
+        );
+    }
+
+    #[test]
+    fn test_display_text() {
+        assert_eq!(
+            TestDisplayProtocol::Tcp.to_string(),
+            "Transmission Control Protocol"
+        );
+        assert_eq!(TestDisplayProtocol::Udp.to_string(), "Udp");
+        assert_eq!(
+            TestDisplayProtocol::from(0x3a).to_string(),
+            "unknown (0x3a)"
+        );
+        assert_eq!(format!("{:?}", TestDisplayProtocol::Tcp), "Tcp");
+        assert_eq!("Tcp".parse(), Ok(TestDisplayProtocol::Tcp));
+    }
+
+    #[test]
+    fn test_try_from_wider() {
+        assert_eq!(
+            TestIpProtocol::try_from_u16(0x06),
+            Some(TestIpProtocol::Tcp)
+        );
+        assert_eq!(
+            TestIpProtocol::try_from_u16(0x99),
+            Some(TestIpProtocol::from(0x99))
+        );
+        assert_eq!(TestIpProtocol::try_from_u16(0x106), None);
+        assert_eq!(TestIpProtocol::try_from_i32(-1), None);
+        assert_eq!(
+            TestIpProtocol::try_from_usize(0x01),
+            Some(TestIpProtocol::Icmp)
+        );
+        assert_eq!(TestOpcode::try_from_i64(-2), Some(TestOpcode::from(-2)));
+        assert_eq!(TestOpcode::try_from_i128(i128::from(i16::MAX) + 1), None);
+    }
+
+    #[test]
+    fn test_is_reserved() {
+        assert!(!TestReservedProtocol::Tcp.is_reserved());
+        assert!(TestReservedProtocol::Experimental.is_reserved());
+        assert!(TestReservedProtocol::Reserved.is_reserved());
+        assert!(!TestReservedProtocol::from(0xfe).is_reserved());
+        assert!(!TestIpProtocol::Tcp.is_reserved());
+    }
+
+    #[test]
+    fn test_flags() {
+        let mask = 0x0103;
+        assert_eq!(TestFlagBit::Ns.to_mask(), 0x100);
+        assert!(TestFlagBit::VARIANTS
+            .iter()
+            .all(|flag| flag.contains_in(mask)));
+        assert!(!TestFlagBit::Syn.contains_in(0x101));
+        assert!(TestFlagBit::from(0x03).contains_in(mask));
+        assert!(!TestFlagBit::from(0x05).contains_in(mask));
+        assert!(!TestFlagBit::from(0).contains_in(mask));
+    }
+
+    #[test]
+    fn test_const_value() {
+        assert_eq!(TestConstProtocol::from(TEST_TCP), TestConstProtocol::Tcp);
+        assert_eq!(
+            TestConstProtocol::checked_from(TEST_TCP),
+            Some(TestConstProtocol::Tcp)
+        );
+        assert_eq!(TestConstProtocol::Tcp.to_repr(), TEST_TCP);
+        assert!(TestConstProtocol::from(0x07).is_unknown());
+    }
+
+    #[test]
+    fn test_strict_declaration_order_aliases() {
+        let (low, lowest) = (
+            TestStrictAliasedPriority::Low,
+            TestStrictAliasedPriority::Lowest,
+        );
+        assert_eq!(low, lowest);
+        assert_eq!(low.cmp(&lowest), Ordering::Equal);
+        assert_eq!(TestStrictAliasedPriority::High.cmp(&lowest), Ordering::Less);
+        let unknown_low = TestStrictAliasedPriority::Unknown {
+            value: 1,
+            _private: _TestStrictAliasedPriority_private::Sealed,
+        };
+        assert_eq!(lowest.cmp(&unknown_low), Ordering::Less);
+        let mut set = std::collections::BTreeSet::new();
+        set.insert(low);
+        assert!(set.contains(&lowest));
+    }
+
+    #[test]
+    fn test_parse_alias() {
+        assert_eq!("Tcp".parse(), Ok(TestNamedProtocol::Tcp));
+        assert_eq!("TCP".parse(), Ok(TestNamedProtocol::Tcp));
+        assert_eq!("Transmission".parse(), Ok(TestNamedProtocol::Tcp));
+        assert!("tcp".parse::<TestNamedProtocol>().is_err());
+        assert_eq!(TestNamedProtocol::Tcp.name(), Some("Tcp"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_parse_alias() -> Result<(), serde_json::Error> {
+        let protocol = serde_json::from_str::<TestNamedProtocol>(r#""TCP""#)?;
+        assert_eq!(protocol, TestNamedProtocol::Tcp);
+        assert_eq!(serde_json::to_string(&protocol)?, r#""Tcp""#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_unknown_name() {
+        assert_eq!(TestOtherProtocol::from(0x3a).to_string(), "other (0x3a)");
+        assert_eq!(TestOtherProtocol::Tcp.to_string(), "Tcp");
+    }
+
+    #[test]
+    fn test_exhaustive() {
+        // no catch-all arm needed.
+        const fn signum(sign: TestSign) -> i8 {
+            match sign {
+                TestSign::Negative => -1,
+                TestSign::Zero => 0,
+                TestSign::Positive => 1,
             }
         }
+        assert!(TestSign::is_exhaustive());
+        for i in i8::MIN..=i8::MAX {
+            let sign = TestSign::from(i);
+            assert!(sign.is_known());
+            assert_eq!(signum(sign), i.signum());
+            assert_eq!(TestSign::try_from_known(i), Ok(sign));
+        }
+        assert_eq!(TestSign::Negative.to_repr(), i8::MIN);
+        assert_eq!(TestSign::Zero.as_repr_or_sentinel(-1), 0);
+        assert_eq!(
+            format!("{:?} {}", TestSign::Zero, TestSign::Positive),
+            "Zero Positive"
+        );
     }
 }
@@ -0,0 +1,1037 @@
+//! Checks that an enum with a full byte's worth of variants, each carrying attributes,
+//! expands within the default recursion limit.
+
+yikes_intenum::yikes_intenum! {
+    pub enum Byte(u8) {
+        /// The byte `0x00`.
+        #[allow(dead_code)]
+        #[yikes(default, long = "byte 0x00")]
+        B00 = 0x00,
+        /// The byte `0x01`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x01")]
+        B01 = 0x01,
+        /// The byte `0x02`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x02")]
+        B02 = 0x02,
+        /// The byte `0x03`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x03")]
+        B03 = 0x03,
+        /// The byte `0x04`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x04")]
+        B04 = 0x04,
+        /// The byte `0x05`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x05")]
+        B05 = 0x05,
+        /// The byte `0x06`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x06")]
+        B06 = 0x06,
+        /// The byte `0x07`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x07")]
+        B07 = 0x07,
+        /// The byte `0x08`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x08")]
+        B08 = 0x08,
+        /// The byte `0x09`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x09")]
+        B09 = 0x09,
+        /// The byte `0x0a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x0a")]
+        B0a = 0x0a,
+        /// The byte `0x0b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x0b")]
+        B0b = 0x0b,
+        /// The byte `0x0c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x0c")]
+        B0c = 0x0c,
+        /// The byte `0x0d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x0d")]
+        B0d = 0x0d,
+        /// The byte `0x0e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x0e")]
+        B0e = 0x0e,
+        /// The byte `0x0f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x0f")]
+        B0f = 0x0f,
+        /// The byte `0x10`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x10")]
+        B10 = 0x10,
+        /// The byte `0x11`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x11")]
+        B11 = 0x11,
+        /// The byte `0x12`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x12")]
+        B12 = 0x12,
+        /// The byte `0x13`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x13")]
+        B13 = 0x13,
+        /// The byte `0x14`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x14")]
+        B14 = 0x14,
+        /// The byte `0x15`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x15")]
+        B15 = 0x15,
+        /// The byte `0x16`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x16")]
+        B16 = 0x16,
+        /// The byte `0x17`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x17")]
+        B17 = 0x17,
+        /// The byte `0x18`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x18")]
+        B18 = 0x18,
+        /// The byte `0x19`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x19")]
+        B19 = 0x19,
+        /// The byte `0x1a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x1a")]
+        B1a = 0x1a,
+        /// The byte `0x1b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x1b")]
+        B1b = 0x1b,
+        /// The byte `0x1c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x1c")]
+        B1c = 0x1c,
+        /// The byte `0x1d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x1d")]
+        B1d = 0x1d,
+        /// The byte `0x1e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x1e")]
+        B1e = 0x1e,
+        /// The byte `0x1f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x1f")]
+        B1f = 0x1f,
+        /// The byte `0x20`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x20")]
+        B20 = 0x20,
+        /// The byte `0x21`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x21")]
+        B21 = 0x21,
+        /// The byte `0x22`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x22")]
+        B22 = 0x22,
+        /// The byte `0x23`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x23")]
+        B23 = 0x23,
+        /// The byte `0x24`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x24")]
+        B24 = 0x24,
+        /// The byte `0x25`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x25")]
+        B25 = 0x25,
+        /// The byte `0x26`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x26")]
+        B26 = 0x26,
+        /// The byte `0x27`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x27")]
+        B27 = 0x27,
+        /// The byte `0x28`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x28")]
+        B28 = 0x28,
+        /// The byte `0x29`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x29")]
+        B29 = 0x29,
+        /// The byte `0x2a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x2a")]
+        B2a = 0x2a,
+        /// The byte `0x2b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x2b")]
+        B2b = 0x2b,
+        /// The byte `0x2c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x2c")]
+        B2c = 0x2c,
+        /// The byte `0x2d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x2d")]
+        B2d = 0x2d,
+        /// The byte `0x2e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x2e")]
+        B2e = 0x2e,
+        /// The byte `0x2f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x2f")]
+        B2f = 0x2f,
+        /// The byte `0x30`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x30")]
+        B30 = 0x30,
+        /// The byte `0x31`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x31")]
+        B31 = 0x31,
+        /// The byte `0x32`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x32")]
+        B32 = 0x32,
+        /// The byte `0x33`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x33")]
+        B33 = 0x33,
+        /// The byte `0x34`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x34")]
+        B34 = 0x34,
+        /// The byte `0x35`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x35")]
+        B35 = 0x35,
+        /// The byte `0x36`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x36")]
+        B36 = 0x36,
+        /// The byte `0x37`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x37")]
+        B37 = 0x37,
+        /// The byte `0x38`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x38")]
+        B38 = 0x38,
+        /// The byte `0x39`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x39")]
+        B39 = 0x39,
+        /// The byte `0x3a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x3a")]
+        B3a = 0x3a,
+        /// The byte `0x3b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x3b")]
+        B3b = 0x3b,
+        /// The byte `0x3c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x3c")]
+        B3c = 0x3c,
+        /// The byte `0x3d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x3d")]
+        B3d = 0x3d,
+        /// The byte `0x3e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x3e")]
+        B3e = 0x3e,
+        /// The byte `0x3f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x3f")]
+        B3f = 0x3f,
+        /// The byte `0x40`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x40")]
+        B40 = 0x40,
+        /// The byte `0x41`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x41")]
+        B41 = 0x41,
+        /// The byte `0x42`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x42")]
+        B42 = 0x42,
+        /// The byte `0x43`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x43")]
+        B43 = 0x43,
+        /// The byte `0x44`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x44")]
+        B44 = 0x44,
+        /// The byte `0x45`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x45")]
+        B45 = 0x45,
+        /// The byte `0x46`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x46")]
+        B46 = 0x46,
+        /// The byte `0x47`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x47")]
+        B47 = 0x47,
+        /// The byte `0x48`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x48")]
+        B48 = 0x48,
+        /// The byte `0x49`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x49")]
+        B49 = 0x49,
+        /// The byte `0x4a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x4a")]
+        B4a = 0x4a,
+        /// The byte `0x4b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x4b")]
+        B4b = 0x4b,
+        /// The byte `0x4c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x4c")]
+        B4c = 0x4c,
+        /// The byte `0x4d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x4d")]
+        B4d = 0x4d,
+        /// The byte `0x4e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x4e")]
+        B4e = 0x4e,
+        /// The byte `0x4f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x4f")]
+        B4f = 0x4f,
+        /// The byte `0x50`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x50")]
+        B50 = 0x50,
+        /// The byte `0x51`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x51")]
+        B51 = 0x51,
+        /// The byte `0x52`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x52")]
+        B52 = 0x52,
+        /// The byte `0x53`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x53")]
+        B53 = 0x53,
+        /// The byte `0x54`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x54")]
+        B54 = 0x54,
+        /// The byte `0x55`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x55")]
+        B55 = 0x55,
+        /// The byte `0x56`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x56")]
+        B56 = 0x56,
+        /// The byte `0x57`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x57")]
+        B57 = 0x57,
+        /// The byte `0x58`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x58")]
+        B58 = 0x58,
+        /// The byte `0x59`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x59")]
+        B59 = 0x59,
+        /// The byte `0x5a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x5a")]
+        B5a = 0x5a,
+        /// The byte `0x5b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x5b")]
+        B5b = 0x5b,
+        /// The byte `0x5c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x5c")]
+        B5c = 0x5c,
+        /// The byte `0x5d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x5d")]
+        B5d = 0x5d,
+        /// The byte `0x5e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x5e")]
+        B5e = 0x5e,
+        /// The byte `0x5f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x5f")]
+        B5f = 0x5f,
+        /// The byte `0x60`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x60")]
+        B60 = 0x60,
+        /// The byte `0x61`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x61")]
+        B61 = 0x61,
+        /// The byte `0x62`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x62")]
+        B62 = 0x62,
+        /// The byte `0x63`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x63")]
+        B63 = 0x63,
+        /// The byte `0x64`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x64")]
+        B64 = 0x64,
+        /// The byte `0x65`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x65")]
+        B65 = 0x65,
+        /// The byte `0x66`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x66")]
+        B66 = 0x66,
+        /// The byte `0x67`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x67")]
+        B67 = 0x67,
+        /// The byte `0x68`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x68")]
+        B68 = 0x68,
+        /// The byte `0x69`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x69")]
+        B69 = 0x69,
+        /// The byte `0x6a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x6a")]
+        B6a = 0x6a,
+        /// The byte `0x6b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x6b")]
+        B6b = 0x6b,
+        /// The byte `0x6c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x6c")]
+        B6c = 0x6c,
+        /// The byte `0x6d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x6d")]
+        B6d = 0x6d,
+        /// The byte `0x6e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x6e")]
+        B6e = 0x6e,
+        /// The byte `0x6f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x6f")]
+        B6f = 0x6f,
+        /// The byte `0x70`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x70")]
+        B70 = 0x70,
+        /// The byte `0x71`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x71")]
+        B71 = 0x71,
+        /// The byte `0x72`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x72")]
+        B72 = 0x72,
+        /// The byte `0x73`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x73")]
+        B73 = 0x73,
+        /// The byte `0x74`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x74")]
+        B74 = 0x74,
+        /// The byte `0x75`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x75")]
+        B75 = 0x75,
+        /// The byte `0x76`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x76")]
+        B76 = 0x76,
+        /// The byte `0x77`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x77")]
+        B77 = 0x77,
+        /// The byte `0x78`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x78")]
+        B78 = 0x78,
+        /// The byte `0x79`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x79")]
+        B79 = 0x79,
+        /// The byte `0x7a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x7a")]
+        B7a = 0x7a,
+        /// The byte `0x7b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x7b")]
+        B7b = 0x7b,
+        /// The byte `0x7c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x7c")]
+        B7c = 0x7c,
+        /// The byte `0x7d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x7d")]
+        B7d = 0x7d,
+        /// The byte `0x7e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x7e")]
+        B7e = 0x7e,
+        /// The byte `0x7f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x7f")]
+        B7f = 0x7f,
+        /// The byte `0x80`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x80")]
+        B80 = 0x80,
+        /// The byte `0x81`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x81")]
+        B81 = 0x81,
+        /// The byte `0x82`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x82")]
+        B82 = 0x82,
+        /// The byte `0x83`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x83")]
+        B83 = 0x83,
+        /// The byte `0x84`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x84")]
+        B84 = 0x84,
+        /// The byte `0x85`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x85")]
+        B85 = 0x85,
+        /// The byte `0x86`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x86")]
+        B86 = 0x86,
+        /// The byte `0x87`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x87")]
+        B87 = 0x87,
+        /// The byte `0x88`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x88")]
+        B88 = 0x88,
+        /// The byte `0x89`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x89")]
+        B89 = 0x89,
+        /// The byte `0x8a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x8a")]
+        B8a = 0x8a,
+        /// The byte `0x8b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x8b")]
+        B8b = 0x8b,
+        /// The byte `0x8c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x8c")]
+        B8c = 0x8c,
+        /// The byte `0x8d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x8d")]
+        B8d = 0x8d,
+        /// The byte `0x8e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x8e")]
+        B8e = 0x8e,
+        /// The byte `0x8f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x8f")]
+        B8f = 0x8f,
+        /// The byte `0x90`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x90")]
+        B90 = 0x90,
+        /// The byte `0x91`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x91")]
+        B91 = 0x91,
+        /// The byte `0x92`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x92")]
+        B92 = 0x92,
+        /// The byte `0x93`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x93")]
+        B93 = 0x93,
+        /// The byte `0x94`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x94")]
+        B94 = 0x94,
+        /// The byte `0x95`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x95")]
+        B95 = 0x95,
+        /// The byte `0x96`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x96")]
+        B96 = 0x96,
+        /// The byte `0x97`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x97")]
+        B97 = 0x97,
+        /// The byte `0x98`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x98")]
+        B98 = 0x98,
+        /// The byte `0x99`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x99")]
+        B99 = 0x99,
+        /// The byte `0x9a`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x9a")]
+        B9a = 0x9a,
+        /// The byte `0x9b`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x9b")]
+        B9b = 0x9b,
+        /// The byte `0x9c`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x9c")]
+        B9c = 0x9c,
+        /// The byte `0x9d`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x9d")]
+        B9d = 0x9d,
+        /// The byte `0x9e`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x9e")]
+        B9e = 0x9e,
+        /// The byte `0x9f`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0x9f")]
+        B9f = 0x9f,
+        /// The byte `0xa0`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa0")]
+        Ba0 = 0xa0,
+        /// The byte `0xa1`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa1")]
+        Ba1 = 0xa1,
+        /// The byte `0xa2`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa2")]
+        Ba2 = 0xa2,
+        /// The byte `0xa3`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa3")]
+        Ba3 = 0xa3,
+        /// The byte `0xa4`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa4")]
+        Ba4 = 0xa4,
+        /// The byte `0xa5`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa5")]
+        Ba5 = 0xa5,
+        /// The byte `0xa6`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa6")]
+        Ba6 = 0xa6,
+        /// The byte `0xa7`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa7")]
+        Ba7 = 0xa7,
+        /// The byte `0xa8`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa8")]
+        Ba8 = 0xa8,
+        /// The byte `0xa9`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xa9")]
+        Ba9 = 0xa9,
+        /// The byte `0xaa`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xaa")]
+        Baa = 0xaa,
+        /// The byte `0xab`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xab")]
+        Bab = 0xab,
+        /// The byte `0xac`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xac")]
+        Bac = 0xac,
+        /// The byte `0xad`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xad")]
+        Bad = 0xad,
+        /// The byte `0xae`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xae")]
+        Bae = 0xae,
+        /// The byte `0xaf`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xaf")]
+        Baf = 0xaf,
+        /// The byte `0xb0`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb0")]
+        Bb0 = 0xb0,
+        /// The byte `0xb1`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb1")]
+        Bb1 = 0xb1,
+        /// The byte `0xb2`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb2")]
+        Bb2 = 0xb2,
+        /// The byte `0xb3`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb3")]
+        Bb3 = 0xb3,
+        /// The byte `0xb4`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb4")]
+        Bb4 = 0xb4,
+        /// The byte `0xb5`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb5")]
+        Bb5 = 0xb5,
+        /// The byte `0xb6`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb6")]
+        Bb6 = 0xb6,
+        /// The byte `0xb7`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb7")]
+        Bb7 = 0xb7,
+        /// The byte `0xb8`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb8")]
+        Bb8 = 0xb8,
+        /// The byte `0xb9`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xb9")]
+        Bb9 = 0xb9,
+        /// The byte `0xba`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xba")]
+        Bba = 0xba,
+        /// The byte `0xbb`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xbb")]
+        Bbb = 0xbb,
+        /// The byte `0xbc`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xbc")]
+        Bbc = 0xbc,
+        /// The byte `0xbd`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xbd")]
+        Bbd = 0xbd,
+        /// The byte `0xbe`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xbe")]
+        Bbe = 0xbe,
+        /// The byte `0xbf`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xbf")]
+        Bbf = 0xbf,
+        /// The byte `0xc0`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc0")]
+        Bc0 = 0xc0,
+        /// The byte `0xc1`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc1")]
+        Bc1 = 0xc1,
+        /// The byte `0xc2`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc2")]
+        Bc2 = 0xc2,
+        /// The byte `0xc3`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc3")]
+        Bc3 = 0xc3,
+        /// The byte `0xc4`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc4")]
+        Bc4 = 0xc4,
+        /// The byte `0xc5`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc5")]
+        Bc5 = 0xc5,
+        /// The byte `0xc6`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc6")]
+        Bc6 = 0xc6,
+        /// The byte `0xc7`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc7")]
+        Bc7 = 0xc7,
+        /// The byte `0xc8`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc8")]
+        Bc8 = 0xc8,
+        /// The byte `0xc9`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xc9")]
+        Bc9 = 0xc9,
+        /// The byte `0xca`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xca")]
+        Bca = 0xca,
+        /// The byte `0xcb`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xcb")]
+        Bcb = 0xcb,
+        /// The byte `0xcc`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xcc")]
+        Bcc = 0xcc,
+        /// The byte `0xcd`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xcd")]
+        Bcd = 0xcd,
+        /// The byte `0xce`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xce")]
+        Bce = 0xce,
+        /// The byte `0xcf`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xcf")]
+        Bcf = 0xcf,
+        /// The byte `0xd0`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd0")]
+        Bd0 = 0xd0,
+        /// The byte `0xd1`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd1")]
+        Bd1 = 0xd1,
+        /// The byte `0xd2`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd2")]
+        Bd2 = 0xd2,
+        /// The byte `0xd3`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd3")]
+        Bd3 = 0xd3,
+        /// The byte `0xd4`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd4")]
+        Bd4 = 0xd4,
+        /// The byte `0xd5`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd5")]
+        Bd5 = 0xd5,
+        /// The byte `0xd6`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd6")]
+        Bd6 = 0xd6,
+        /// The byte `0xd7`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd7")]
+        Bd7 = 0xd7,
+        /// The byte `0xd8`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd8")]
+        Bd8 = 0xd8,
+        /// The byte `0xd9`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xd9")]
+        Bd9 = 0xd9,
+        /// The byte `0xda`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xda")]
+        Bda = 0xda,
+        /// The byte `0xdb`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xdb")]
+        Bdb = 0xdb,
+        /// The byte `0xdc`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xdc")]
+        Bdc = 0xdc,
+        /// The byte `0xdd`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xdd")]
+        Bdd = 0xdd,
+        /// The byte `0xde`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xde")]
+        Bde = 0xde,
+        /// The byte `0xdf`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xdf")]
+        Bdf = 0xdf,
+        /// The byte `0xe0`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe0")]
+        Be0 = 0xe0,
+        /// The byte `0xe1`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe1")]
+        Be1 = 0xe1,
+        /// The byte `0xe2`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe2")]
+        Be2 = 0xe2,
+        /// The byte `0xe3`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe3")]
+        Be3 = 0xe3,
+        /// The byte `0xe4`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe4")]
+        Be4 = 0xe4,
+        /// The byte `0xe5`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe5")]
+        Be5 = 0xe5,
+        /// The byte `0xe6`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe6")]
+        Be6 = 0xe6,
+        /// The byte `0xe7`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe7")]
+        Be7 = 0xe7,
+        /// The byte `0xe8`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe8")]
+        Be8 = 0xe8,
+        /// The byte `0xe9`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xe9")]
+        Be9 = 0xe9,
+        /// The byte `0xea`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xea")]
+        Bea = 0xea,
+        /// The byte `0xeb`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xeb")]
+        Beb = 0xeb,
+        /// The byte `0xec`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xec")]
+        Bec = 0xec,
+        /// The byte `0xed`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xed")]
+        Bed = 0xed,
+        /// The byte `0xee`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xee")]
+        Bee = 0xee,
+        /// The byte `0xef`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xef")]
+        Bef = 0xef,
+        /// The byte `0xf0`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf0")]
+        Bf0 = 0xf0,
+        /// The byte `0xf1`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf1")]
+        Bf1 = 0xf1,
+        /// The byte `0xf2`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf2")]
+        Bf2 = 0xf2,
+        /// The byte `0xf3`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf3")]
+        Bf3 = 0xf3,
+        /// The byte `0xf4`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf4")]
+        Bf4 = 0xf4,
+        /// The byte `0xf5`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf5")]
+        Bf5 = 0xf5,
+        /// The byte `0xf6`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf6")]
+        Bf6 = 0xf6,
+        /// The byte `0xf7`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf7")]
+        Bf7 = 0xf7,
+        /// The byte `0xf8`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf8")]
+        Bf8 = 0xf8,
+        /// The byte `0xf9`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xf9")]
+        Bf9 = 0xf9,
+        /// The byte `0xfa`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xfa")]
+        Bfa = 0xfa,
+        /// The byte `0xfb`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xfb")]
+        Bfb = 0xfb,
+        /// The byte `0xfc`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xfc")]
+        Bfc = 0xfc,
+        /// The byte `0xfd`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xfd")]
+        Bfd = 0xfd,
+        /// The byte `0xfe`.
+        #[allow(dead_code)]
+        #[yikes(long = "byte 0xfe")]
+        Bfe = 0xfe,
+    }
+}
+
+#[test]
+fn test_many_variants() {
+    assert_eq!(Byte::default(), Byte::B00);
+    assert_eq!(Byte::from(0x00), Byte::B00);
+    assert_eq!(Byte::from(0xfe), Byte::Bfe);
+    assert!(Byte::from(0xff).is_unknown());
+    assert_eq!(Byte::B7f.long_name(), Some("byte 0x7f"));
+    assert_eq!("B80".parse(), Ok(Byte::B80));
+}
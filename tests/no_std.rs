@@ -0,0 +1,33 @@
+//! Checks that generated code only needs `core` (and `alloc`, with its feature), by
+//! expanding the macro in a `#![no_std]` crate, where any `std` path fails to resolve.
+#![no_std]
+
+yikes_intenum::yikes_intenum! {
+    /// IP datagram encapsulated protocol.
+    #[yikes(sorted, classify(0x90..=0xfc => "unassigned"))]
+    pub enum Protocol(u8) {
+        Icmp = 0x01,
+        #[yikes(default, long = "Transmission Control Protocol")]
+        Tcp = 0x06,
+        Udp = 0x11,
+    }
+}
+
+yikes_intenum::yikes_intenum! {
+    #[yikes(serde_as = "str", ord_by_declaration, strict_eq, unknown = Other)]
+    pub enum Named(i32) {
+        Low = -1,
+        High = 1,
+    }
+}
+
+#[test]
+fn test_no_std() {
+    assert_eq!(Protocol::from(0x06), Protocol::Tcp);
+    assert_eq!(Protocol::default(), Protocol::Tcp);
+    assert_eq!(u8::from(Protocol::Udp), 0x11);
+    assert_eq!("Icmp".parse(), Ok(Protocol::Icmp));
+    assert_eq!(Protocol::classify(0x90), Some("unassigned"));
+    assert!(Named::from(0).is_unknown());
+    assert!(Named::Low < Named::High);
+}
@@ -0,0 +1,55 @@
+//! Checks that every value, named or not, round-trips through compact binary formats,
+//! which only works if values serialize as the underlying integer.
+#![cfg(feature = "serde")]
+
+yikes_intenum::yikes_intenum! {
+    pub enum Protocol(u8) {
+        Icmp = 0x01,
+        Tcp = 0x06,
+        Udp = 0x11,
+    }
+}
+
+yikes_intenum::yikes_intenum! {
+    pub enum Opcode(i16) {
+        Nop = -1,
+        Load = 0,
+        Store = 1,
+    }
+}
+
+#[test]
+fn test_postcard_round_trip() {
+    for value in u8::MIN..=u8::MAX {
+        let protocol = Protocol::from(value);
+        let bytes = postcard::to_allocvec(&protocol).ok();
+        assert_eq!(bytes, postcard::to_allocvec(&value).ok());
+        let decoded = bytes.and_then(|bytes| postcard::from_bytes::<Protocol>(&bytes).ok());
+        assert_eq!(decoded.map(Protocol::to_repr), Some(value));
+    }
+    for value in i16::MIN..=i16::MAX {
+        let opcode = Opcode::from(value);
+        let bytes = postcard::to_allocvec(&opcode).ok();
+        assert_eq!(bytes, postcard::to_allocvec(&value).ok());
+        let decoded = bytes.and_then(|bytes| postcard::from_bytes::<Opcode>(&bytes).ok());
+        assert_eq!(decoded.map(Opcode::to_repr), Some(value));
+    }
+}
+
+#[test]
+fn test_bincode_round_trip() {
+    for value in u8::MIN..=u8::MAX {
+        let protocol = Protocol::from(value);
+        let bytes = bincode::serialize(&protocol).ok();
+        assert_eq!(bytes, bincode::serialize(&value).ok());
+        let decoded = bytes.and_then(|bytes| bincode::deserialize::<Protocol>(&bytes).ok());
+        assert_eq!(decoded.map(Protocol::to_repr), Some(value));
+    }
+    for value in i16::MIN..=i16::MAX {
+        let opcode = Opcode::from(value);
+        let bytes = bincode::serialize(&opcode).ok();
+        assert_eq!(bytes, bincode::serialize(&value).ok());
+        let decoded = bytes.and_then(|bytes| bincode::deserialize::<Opcode>(&bytes).ok());
+        assert_eq!(decoded.map(Opcode::to_repr), Some(value));
+    }
+}